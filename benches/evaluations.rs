@@ -0,0 +1,118 @@
+use ark_ff::{AdditiveGroup, Field};
+use divan::{black_box, AllocProfiler, Bencher};
+use whir::algebra::{
+    embedding::Basefield,
+    fields::{Field64 as F, Field64_2 as EF},
+    sumcheck::{
+        compute_product_sumcheck_polynomial, compute_sumcheck_polynomial, fold,
+        mixed_compute_product_sumcheck_polynomial, mixed_compute_sumcheck_polynomial, mixed_fold,
+        ProductCovector,
+    },
+};
+
+#[global_allocator]
+static ALLOC: AllocProfiler = AllocProfiler::system();
+
+/// Number of evaluation points.
+const POINTS: &[usize] = &[5, 8, 16, 32];
+
+/// Variables of the vector and rounds of the initial sumcheck.
+const NUM_VARIABLES: &[usize] = &[16, 20];
+const NUM_ROUNDS: usize = 4;
+
+fn evaluations_inputs(
+    num_variables: usize,
+    count: usize,
+) -> (Vec<F>, Vec<ProductCovector<EF>>, EF) {
+    let embedding = Basefield::<EF>::new();
+    let a = (0..1_u64 << num_variables).map(F::from).collect::<Vec<_>>();
+    let covectors = (0..count as u64)
+        .map(|i| {
+            let point = (0..num_variables as u64)
+                .map(|j| EF::new(F::from(i * 1000 + j + 2), F::from(j + 5)))
+                .collect::<Vec<_>>();
+            ProductCovector::eq(&point, EF::from(i + 3))
+        })
+        .collect::<Vec<_>>();
+    let sum = covectors
+        .iter()
+        .map(|covector| covector.mixed_dot(&embedding, &a))
+        .sum();
+    (a, covectors, sum)
+}
+
+/// The sumcheck polynomial `c0 + c1 X + c2 X^2` with `p(0) + p(1) = sum` evaluated at `weight`.
+fn evaluate(c0: EF, c2: EF, sum: EF, weight: EF) -> EF {
+    let c1 = sum - c0.double() - c2;
+    c0 + (c1 + c2 * weight) * weight
+}
+
+/// Runs `num_rounds` rounds of the sumcheck with the covectors kept factored.
+fn product_sumcheck(
+    a: &[F],
+    mut covectors: Vec<ProductCovector<EF>>,
+    mut sum: EF,
+    num_rounds: usize,
+) -> Vec<EF> {
+    let embedding = Basefield::<EF>::new();
+    let (c0, c2) = mixed_compute_product_sumcheck_polynomial(&embedding, a, &covectors, sum);
+    let weight = EF::from(42);
+    sum = evaluate(c0, c2, sum, weight);
+    let mut a = mixed_fold(&embedding, a, weight);
+    for covector in &mut covectors {
+        covector.fold(weight);
+    }
+    for round in 1..num_rounds {
+        let (c0, c2) = compute_product_sumcheck_polynomial(&a, &covectors, sum);
+        let weight = EF::from(round as u64 + 42);
+        sum = evaluate(c0, c2, sum, weight);
+        fold(&mut a, weight);
+        for covector in &mut covectors {
+            covector.fold(weight);
+        }
+    }
+    a
+}
+
+/// The initial sumcheck with the evaluation points materialized.
+#[divan::bench(consts = NUM_VARIABLES, args = POINTS)]
+fn evaluations_dense<const N: usize>(bencher: Bencher, count: usize) {
+    let embedding = Basefield::<EF>::new();
+    bencher
+        .with_inputs(|| evaluations_inputs(N, count))
+        .bench_values(|(a, covectors, _)| {
+            let mut b = vec![EF::ZERO; a.len()];
+            for covector in &covectors {
+                covector.accumulate(&mut b);
+            }
+            let poly = mixed_compute_sumcheck_polynomial(&embedding, &a, &b);
+            black_box(poly);
+            let mut a = mixed_fold(&embedding, &a, EF::from(42));
+            fold(&mut b, EF::from(42));
+            for round in 1..NUM_ROUNDS {
+                let poly = compute_sumcheck_polynomial(&a, &b);
+                black_box(poly);
+                fold(&mut a, EF::from(round as u64 + 42));
+                fold(&mut b, EF::from(round as u64 + 42));
+            }
+            black_box((a, b))
+        });
+}
+
+/// The reduction of the evaluation points to a single point, followed by the initial
+/// sumcheck with the single point kept factored.
+#[divan::bench(consts = NUM_VARIABLES, args = POINTS)]
+fn evaluations_reduced<const N: usize>(bencher: Bencher, count: usize) {
+    bencher
+        .with_inputs(|| evaluations_inputs(N, count))
+        .bench_values(|(a, covectors, sum)| {
+            let reduced = product_sumcheck(&a, covectors, sum, N);
+            let point = (0..N as u64).map(|i| EF::from(i + 42)).collect::<Vec<_>>();
+            let covector = ProductCovector::eq(&point, EF::ONE);
+            black_box(product_sumcheck(&a, vec![covector], reduced[0], NUM_ROUNDS))
+        });
+}
+
+fn main() {
+    divan::main();
+}
@@ -1,5 +1,6 @@
+use ark_ff::Field;
 use divan::{black_box, AllocProfiler, Bencher};
-use whir::algebra::{fields::Field64, ntt};
+use whir::algebra::{fields::Field64, ntt, Basis};
 
 #[global_allocator]
 static ALLOC: AllocProfiler = AllocProfiler::system();
@@ -35,6 +36,8 @@ fn interleaved_rs_encode(bencher: Bencher, case: &(u32, usize, usize)) {
                 &[&coeffs],
                 expansion,
                 1 << coset_sz,
+                Basis::Coefficients,
+                Field64::ONE,
             ))
         });
 }
@@ -1,7 +1,9 @@
 use divan::{black_box, AllocProfiler, Bencher};
 use whir::algebra::{
     fields::Field64 as F,
-    sumcheck::{compute_sumcheck_polynomial, fold},
+    sumcheck::{
+        compute_product_sumcheck_polynomial, compute_sumcheck_polynomial, fold, ProductCovector,
+    },
 };
 
 #[global_allocator]
@@ -9,6 +11,13 @@ static ALLOC: AllocProfiler = AllocProfiler::system();
 
 const SIZES: &[u64] = &[1 << 16, 1 << 18, 1 << 20];
 
+/// Number of eq covectors in the initial sumcheck benchmarks.
+const COVECTORS: &[usize] = &[1, 2, 4, 8, 16];
+
+/// Variables and rounds of the initial sumcheck benchmarks.
+const NUM_VARIABLES: usize = 20;
+const NUM_ROUNDS: usize = 4;
+
 #[divan::bench(args = SIZES)]
 fn sumcheck_first_round(bencher: Bencher, size: u64) {
     bencher
@@ -27,6 +36,59 @@ fn sumcheck_first_round(bencher: Bencher, size: u64) {
         });
 }
 
+fn initial_sumcheck_inputs(count: usize) -> (Vec<F>, Vec<ProductCovector<F>>, F) {
+    let a = (0..1_u64 << NUM_VARIABLES).map(F::from).collect::<Vec<_>>();
+    let covectors = (0..count as u64)
+        .map(|i| {
+            let point = (0..NUM_VARIABLES as u64)
+                .map(|j| F::from(i * 1000 + j + 2))
+                .collect::<Vec<_>>();
+            ProductCovector::eq(&point, F::from(i + 3))
+        })
+        .collect::<Vec<_>>();
+    let sum = covectors.iter().map(|covector| covector.dot(&a)).sum();
+    (a, covectors, sum)
+}
+
+/// The initial sumcheck with the covectors materialized.
+#[divan::bench(args = COVECTORS)]
+fn initial_sumcheck_dense(bencher: Bencher, count: usize) {
+    bencher
+        .with_inputs(|| initial_sumcheck_inputs(count))
+        .bench_values(|(mut a, covectors, _)| {
+            let mut b = vec![F::from(0_u64); a.len()];
+            for covector in &covectors {
+                covector.accumulate(&mut b);
+            }
+            for round in 0..NUM_ROUNDS {
+                let poly = compute_sumcheck_polynomial(&a, &b);
+                black_box(poly);
+                fold(&mut a, F::from(round as u64 + 42));
+                fold(&mut b, F::from(round as u64 + 42));
+            }
+            black_box((a, b))
+        });
+}
+
+/// The initial sumcheck with the covectors kept factored.
+#[divan::bench(args = COVECTORS)]
+fn initial_sumcheck_product(bencher: Bencher, count: usize) {
+    bencher
+        .with_inputs(|| initial_sumcheck_inputs(count))
+        .bench_values(|(mut a, mut covectors, sum)| {
+            for round in 0..NUM_ROUNDS {
+                let poly = compute_product_sumcheck_polynomial(&a, &covectors, sum);
+                black_box(poly);
+                let weight = F::from(round as u64 + 42);
+                fold(&mut a, weight);
+                for covector in &mut covectors {
+                    covector.fold(weight);
+                }
+            }
+            black_box((a, covectors))
+        });
+}
+
 fn main() {
     divan::main();
 }
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Basis in which a committed vector represents its polynomial.
+///
+/// A vector $v ∈ 𝔽^n$ with $n = 2^k$ is Reed-Solomon encoded as the univariate polynomial
+/// $sum_i c_i · X^i$, where $c$ depends on the basis:
+///
+/// - [`Basis::Coefficients`] uses $c = v$ directly.
+/// - [`Basis::Evaluations`] takes $v$ to be the evaluations $v_i = f(bits(i))$ of a multilinear
+///   $f$ on the boolean hypercube and uses its monomial coefficients, i.e. the inverse wavelet
+///   transform of $v$. Evaluating the encoded polynomial in $x$ then equals $f$ in
+///   $(x^(2^(k-1)), …, x^2, x)$, see [`super::MultilinearPoint::expand_from_univariate`].
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub enum Basis {
+    #[default]
+    Coefficients,
+    Evaluations,
+}
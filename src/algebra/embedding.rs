@@ -2,10 +2,14 @@
 
 use std::fmt::Debug;
 
-use ark_ff::Field;
+use ark_ff::{CubicExtConfig, CubicExtField, Field};
+use derive_where::derive_where;
 use serde::{Deserialize, Serialize};
 
-use crate::type_info::{Type, TypeInfo, Typed};
+use crate::{
+    algebra::fields::BinaryTowerField,
+    type_info::{Type, TypeInfo, Typed},
+};
 
 /// Trait for a type representing a unital field homomorphism.
 ///
@@ -58,6 +62,16 @@ pub struct Basefield<F: Field> {
     extension: Type<F>,
 }
 
+/// The embedding of the base field of a cubic extension, e.g. of
+/// [`Field64_2`](crate::algebra::fields::Field64_2) in
+/// [`Field64_6`](crate::algebra::fields::Field64_6).
+#[derive_where(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CubicExtension<P: CubicExtConfig> {
+    extension: Type<CubicExtField<P>>,
+}
+
 /// The Frobenius automorphism.
 ///
 /// It demonstrates that embeddings are not uniquely defined by their source
@@ -81,6 +95,18 @@ pub struct Compose<A: Embedding, B: Embedding<Source = A::Target>> {
     outer: Typed<B>,
 }
 
+/// The embedding of a binary tower field into a larger level of the tower.
+///
+/// Elements are embedded by zero-extending their bits, see [`BinaryTowerField`].
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct Subfield<F: BinaryTowerField, G: BinaryTowerField> {
+    subfield: Type<F>,
+    field: Type<G>,
+}
+
 impl<F: Field> Identity<F> {
     pub fn new() -> Self {
         Self::default()
@@ -93,6 +119,12 @@ impl<F: Field> Basefield<F> {
     }
 }
 
+impl<P: CubicExtConfig> CubicExtension<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 impl<F: Field> Frobenius<F> {
     pub fn new(power: u64) -> Self {
         Self {
@@ -102,6 +134,13 @@ impl<F: Field> Frobenius<F> {
     }
 }
 
+impl<F: BinaryTowerField, G: BinaryTowerField> Subfield<F, G> {
+    pub fn new() -> Self {
+        assert!(F::extension_degree() <= G::extension_degree());
+        Self::default()
+    }
+}
+
 impl<A, B> Compose<A, B>
 where
     A: Embedding,
@@ -153,6 +192,22 @@ impl<F: Field> Embedding for Basefield<F> {
     }
 }
 
+impl<P: CubicExtConfig> Embedding for CubicExtension<P> {
+    type Source = P::BaseField;
+    type Target = CubicExtField<P>;
+
+    #[inline]
+    fn map(&self, dom: Self::Source) -> Self::Target {
+        CubicExtField::new(dom, P::BaseField::ZERO, P::BaseField::ZERO)
+    }
+
+    #[inline]
+    fn mixed_mul(&self, mut cod: Self::Target, dom: Self::Source) -> Self::Target {
+        cod.mul_assign_by_base_field(&dom);
+        cod
+    }
+}
+
 impl<F: Field> Embedding for Frobenius<F> {
     type Source = F;
     type Target = F;
@@ -164,6 +219,21 @@ impl<F: Field> Embedding for Frobenius<F> {
     }
 }
 
+impl<F: BinaryTowerField, G: BinaryTowerField> Embedding for Subfield<F, G> {
+    type Source = F;
+    type Target = G;
+
+    #[inline]
+    fn map(&self, dom: Self::Source) -> Self::Target {
+        G::from_u128(dom.to_u128()).expect("Subfield larger than field")
+    }
+
+    #[inline]
+    fn mixed_mul(&self, cod: Self::Target, dom: Self::Source) -> Self::Target {
+        cod.mul_subfield(dom)
+    }
+}
+
 impl<A, B> Embedding for Compose<A, B>
 where
     A: Embedding,
@@ -204,6 +274,14 @@ impl<F: Field> TypeInfo for Basefield<F> {
     }
 }
 
+impl<P: CubicExtConfig> TypeInfo for CubicExtension<P> {
+    type Info = String;
+
+    fn type_info() -> Self::Info {
+        "cubic_extension".into()
+    }
+}
+
 impl<F: Field> TypeInfo for Frobenius<F> {
     type Info = String;
 
@@ -212,6 +290,14 @@ impl<F: Field> TypeInfo for Frobenius<F> {
     }
 }
 
+impl<F: BinaryTowerField, G: BinaryTowerField> TypeInfo for Subfield<F, G> {
+    type Info = String;
+
+    fn type_info() -> Self::Info {
+        "subfield".into()
+    }
+}
+
 impl<A: Embedding, B: Embedding<Source = A::Target>> TypeInfo for Compose<A, B> {
     type Info = String;
 
@@ -222,7 +308,7 @@ impl<A: Embedding, B: Embedding<Source = A::Target>> TypeInfo for Compose<A, B>
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use ark_ff::{AdditiveGroup, Field, PrimeField};
+    use ark_ff::{AdditiveGroup, Field, Fp6ConfigWrapper, PrimeField};
     use proptest::{
         collection,
         prelude::{any, Just, Strategy},
@@ -277,4 +363,33 @@ pub(crate) mod tests {
         test_embedding(&Frobenius::<fields::Field64_3>::new(1));
         test_embedding(&Frobenius::<fields::Field64_3>::new(2));
     }
+
+    #[test]
+    fn test_field64_6() {
+        type Extension = CubicExtension<Fp6ConfigWrapper<fields::F6Config64>>;
+
+        test_embedding(&Extension::new());
+        test_embedding(&Compose::new(
+            Basefield::<fields::Field64_2>::new(),
+            Extension::new(),
+        ));
+        test_embedding(&Compose::new(
+            Frobenius::<fields::Field64_2>::new(1),
+            Extension::new(),
+        ));
+    }
+
+    #[test]
+    fn test_binary_tower() {
+        use fields::{BinaryField128, BinaryField16, BinaryField32, BinaryField8};
+
+        test_embedding(&Subfield::<BinaryField8, BinaryField8>::new());
+        test_embedding(&Subfield::<BinaryField8, BinaryField128>::new());
+        test_embedding(&Subfield::<BinaryField16, BinaryField32>::new());
+        test_embedding(&Subfield::<BinaryField32, BinaryField128>::new());
+        test_embedding(&Compose::new(
+            Subfield::<BinaryField8, BinaryField32>::new(),
+            Frobenius::<BinaryField32>::new(3),
+        ));
+    }
 }
@@ -1,10 +1,20 @@
+mod binary;
+mod complex;
+mod quintic;
+
 use ark_ff::{
-    Field, Fp128, Fp192, Fp2, Fp256, Fp2Config, Fp3, Fp3Config, Fp64, MontBackend, MontConfig,
-    MontFp, PrimeField,
+    Field, Fp128, Fp192, Fp2, Fp256, Fp2Config, Fp3, Fp3Config, Fp4, Fp4Config, Fp6, Fp64,
+    Fp6Config, MontBackend, MontConfig, MontFp, PrimeField,
 };
 use serde::{Deserialize, Serialize};
 use zerocopy::IntoBytes;
 
+pub use self::binary::{
+    BinaryField1, BinaryField128, BinaryField16, BinaryField1Config, BinaryField32, BinaryField64,
+    BinaryField8, BinaryTowerField,
+};
+pub use self::complex::{Mersenne31Complex, Mersenne31ComplexConfig};
+pub use self::quintic::{DecodingBuffer, QuinticConfig, QuinticExtension};
 use crate::type_info::TypeInfo;
 
 pub trait FieldWithSize {
@@ -16,7 +26,13 @@ where
     F: Field,
 {
     fn field_size_in_bits() -> usize {
-        F::BasePrimeField::MODULUS_BIT_SIZE as usize * F::extension_degree() as usize
+        // `𝔽_2` has one bit, although its modulus takes two.
+        let base_bits = if F::characteristic() == [2] {
+            1
+        } else {
+            F::BasePrimeField::MODULUS_BIT_SIZE as usize
+        };
+        base_bits * F::extension_degree() as usize
     }
 }
 
@@ -129,8 +145,198 @@ impl Fp3Config for F3Config64 {
         &[0x80000002fffffffe, 0x80000002fffffffc, 0x7ffffffe];
 }
 
+/// Goldilocks sextic extension `𝔽_{p^2}[v] / (v^3 - 2)` over [`Field64_2`].
+pub type Field64_6 = Fp6<F6Config64>;
+#[derive(Clone, Copy)]
+pub struct F6Config64;
+
+impl Fp6Config for F6Config64 {
+    type Fp2Config = F2Config64;
+
+    const NONRESIDUE: Field64_2 = Fp2::new(MontFp!("2"), MontFp!("0"));
+
+    const FROBENIUS_COEFF_FP6_C1: &'static [Field64_2] = &[
+        // Fq2(2)**(((q^0) - 1) / 3)
+        Fp2::new(MontFp!("1"), MontFp!("0")),
+        // Fq2(2)**(((q^1) - 1) / 3)
+        Fp2::new(MontFp!("4294967295"), MontFp!("0")),
+        // Fq2(2)**(((q^2) - 1) / 3)
+        Fp2::new(MontFp!("18446744065119617025"), MontFp!("0")),
+        // Fq2(2)**(((q^3) - 1) / 3)
+        Fp2::new(MontFp!("1"), MontFp!("0")),
+        // Fq2(2)**(((q^4) - 1) / 3)
+        Fp2::new(MontFp!("4294967295"), MontFp!("0")),
+        // Fq2(2)**(((q^5) - 1) / 3)
+        Fp2::new(MontFp!("18446744065119617025"), MontFp!("0")),
+    ];
+
+    const FROBENIUS_COEFF_FP6_C2: &'static [Field64_2] = &[
+        // Fq2(2)**(((2q^0) - 2) / 3)
+        Fp2::new(MontFp!("1"), MontFp!("0")),
+        // Fq2(2)**(((2q^1) - 2) / 3)
+        Fp2::new(MontFp!("18446744065119617025"), MontFp!("0")),
+        // Fq2(2)**(((2q^2) - 2) / 3)
+        Fp2::new(MontFp!("4294967295"), MontFp!("0")),
+        // Fq2(2)**(((2q^3) - 2) / 3)
+        Fp2::new(MontFp!("1"), MontFp!("0")),
+        // Fq2(2)**(((2q^4) - 2) / 3)
+        Fp2::new(MontFp!("18446744065119617025"), MontFp!("0")),
+        // Fq2(2)**(((2q^5) - 2) / 3)
+        Fp2::new(MontFp!("4294967295"), MontFp!("0")),
+    ];
+}
+
+/// BabyBear prime `15 · 2^27 + 1`.
+#[derive(MontConfig)]
+#[modulus = "2013265921"]
+#[generator = "31"]
+pub struct BabyBearConfig;
+pub type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+
+/// Quartic extension of BabyBear `𝔽_p[X] / (X^4 - 11)`.
+///
+/// Built as the tower `𝔽_p[u] / (u^2 - 11)` then `[X] / (X^2 - u)`.
+pub type BabyBear4 = Fp4<BabyBear4Config>;
+pub struct BabyBear2Config;
+impl Fp2Config for BabyBear2Config {
+    type Fp = BabyBear;
+
+    const NONRESIDUE: Self::Fp = MontFp!("11");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Self::Fp] = &[
+        // 11^((p^0 - 1) / 2)
+        MontFp!("1"),
+        // 11^((p^1 - 1) / 2)
+        MontFp!("2013265920"),
+    ];
+}
+
+pub struct BabyBear4Config;
+impl Fp4Config for BabyBear4Config {
+    type Fp2Config = BabyBear2Config;
+
+    const NONRESIDUE: Fp2<Self::Fp2Config> = Fp2::new(MontFp!("0"), MontFp!("1"));
+
+    const FROBENIUS_COEFF_FP4_C1: &'static [BabyBear] = &[
+        // 11^((p^i - 1) / 4) for i = 0, 1, 2, 3
+        MontFp!("1"),
+        MontFp!("1728404513"),
+        MontFp!("2013265920"),
+        MontFp!("284861408"),
+    ];
+}
+
+/// KoalaBear prime `2^31 - 2^24 + 1`.
+#[derive(MontConfig)]
+#[modulus = "2130706433"]
+#[generator = "3"]
+pub struct KoalaBearConfig;
+pub type KoalaBear = Fp64<MontBackend<KoalaBearConfig, 1>>;
+
+/// Quartic extension of KoalaBear `𝔽_p[X] / (X^4 - 3)`.
+///
+/// Built as the tower `𝔽_p[u] / (u^2 - 3)` then `[X] / (X^2 - u)`.
+pub type KoalaBear4 = Fp4<KoalaBear4Config>;
+pub struct KoalaBear2Config;
+impl Fp2Config for KoalaBear2Config {
+    type Fp = KoalaBear;
+
+    const NONRESIDUE: Self::Fp = MontFp!("3");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Self::Fp] = &[
+        // 3^((p^0 - 1) / 2)
+        MontFp!("1"),
+        // 3^((p^1 - 1) / 2)
+        MontFp!("2130706432"),
+    ];
+}
+
+pub struct KoalaBear4Config;
+impl Fp4Config for KoalaBear4Config {
+    type Fp2Config = KoalaBear2Config;
+
+    const NONRESIDUE: Fp2<Self::Fp2Config> = Fp2::new(MontFp!("0"), MontFp!("1"));
+
+    const FROBENIUS_COEFF_FP4_C1: &'static [KoalaBear] = &[
+        // 3^((p^i - 1) / 4) for i = 0, 1, 2, 3
+        MontFp!("1"),
+        MontFp!("2113994754"),
+        MontFp!("2130706432"),
+        MontFp!("16711679"),
+    ];
+}
+
+/// Quintic extension of BabyBear `𝔽_p[X] / (X^5 - 2)`.
+pub type BabyBear5 = QuinticExtension<BabyBear5Config>;
+pub struct BabyBear5Config;
+impl QuinticConfig for BabyBear5Config {
+    type Fp = BabyBear;
+
+    const REDUCTION: [BabyBear; 5] = [
+        MontFp!("2"),
+        MontFp!("0"),
+        MontFp!("0"),
+        MontFp!("0"),
+        MontFp!("0"),
+    ];
+
+    // X + 8
+    const GENERATOR: [BabyBear; 5] = [
+        MontFp!("8"),
+        MontFp!("1"),
+        MontFp!("0"),
+        MontFp!("0"),
+        MontFp!("0"),
+    ];
+
+    const TRACE_MINUS_ONE_DIV_TWO: &'static [u64] = &[0x7ac0_0023_2800_0025, 0x5cb2_7803_dcc5_0010];
+}
+
+/// Quintic extension of KoalaBear `𝔽_p[X] / (X^5 + X^2 - 1)`.
+///
+/// As five does not divide `p - 1`, no binomial `X^5 - a` is irreducible.
+pub type KoalaBear5 = QuinticExtension<KoalaBear5Config>;
+pub struct KoalaBear5Config;
+impl QuinticConfig for KoalaBear5Config {
+    type Fp = KoalaBear;
+
+    const REDUCTION: [KoalaBear; 5] = [
+        MontFp!("1"),
+        MontFp!("0"),
+        MontFp!("-1"),
+        MontFp!("0"),
+        MontFp!("0"),
+    ];
+
+    // X + 2
+    const GENERATOR: [KoalaBear; 5] = [
+        MontFp!("2"),
+        MontFp!("1"),
+        MontFp!("0"),
+        MontFp!("0"),
+        MontFp!("0"),
+    ];
+
+    const TRACE_MINUS_ONE_DIV_TWO: &'static [u64] =
+        &[0x477b_013b_0500_013d, 0xd89e_c166_43bb_031c, 0x3];
+}
+
+/// Mersenne prime `2^31 - 1`.
+///
+/// The multiplicative group has two-adicity one, so this field has no radix-2 NTT and vectors
+/// over it cannot be committed to with WHIR. Commit over [`Mersenne31Complex`] instead, whose
+/// multiplicative group has a subgroup of order `2^32`.
+#[derive(MontConfig)]
+#[modulus = "2147483647"]
+#[generator = "7"]
+pub struct Mersenne31Config;
+pub type Mersenne31 = Fp64<MontBackend<Mersenne31Config, 1>>;
+
 #[cfg(test)]
 mod tests {
+    use ark_ff::{AdditiveGroup, FftField};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::UniformRand;
     use static_assertions::const_assert_eq;
 
     use super::*;
@@ -152,6 +358,102 @@ mod tests {
         assert_eq!(type_info.extension_degree, 3);
     }
 
+    /// Checks the defining relation and the Frobenius coefficients of a quartic extension.
+    fn check_quartic<P: Fp4Config>(nonresidue: u64) {
+        let x = Fp4::<P>::new(Fp2::ZERO, Fp2::ONE);
+        assert_eq!(x.pow([4]), Fp4::<P>::from(nonresidue));
+        let mut expected = x;
+        for power in 1..4 {
+            expected = expected.pow(<<Fp4<P> as Field>::BasePrimeField as PrimeField>::MODULUS);
+            let mut frobenius = x;
+            frobenius.frobenius_map_in_place(power);
+            assert_eq!(frobenius, expected);
+        }
+    }
+
+    #[test]
+    fn test_quartic_extensions() {
+        check_quartic::<BabyBear4Config>(11);
+        check_quartic::<KoalaBear4Config>(3);
+        assert_eq!(BabyBear4::extension_degree(), 4);
+        assert_eq!(KoalaBear4::field_size_in_bits(), 124);
+    }
+
+    /// Checks the defining relation, Frobenius, inverses and square roots of a quintic
+    /// extension, and that the generator is a non-residue.
+    fn check_quintic<P: QuinticConfig>() {
+        let x = QuinticExtension::<P>::new([
+            P::Fp::ZERO,
+            P::Fp::ONE,
+            P::Fp::ZERO,
+            P::Fp::ZERO,
+            P::Fp::ZERO,
+        ]);
+        assert_eq!(x.pow([5]), QuinticExtension::new(P::REDUCTION));
+        assert!(QuinticExtension::<P>::GENERATOR.legendre().is_qnr());
+        let mut rng = ark_std::test_rng();
+        for _ in 0..10 {
+            let a = QuinticExtension::<P>::rand(&mut rng);
+            assert_eq!(a * a.inverse().unwrap(), QuinticExtension::ONE);
+            assert_eq!(a.square().sqrt().unwrap().square(), a.square());
+            let mut expected = a;
+            for power in 1..5 {
+                expected = expected.pow(P::Fp::MODULUS);
+                let mut frobenius = a;
+                frobenius.frobenius_map_in_place(power);
+                assert_eq!(frobenius, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quintic_extensions() {
+        check_quintic::<BabyBear5Config>();
+        check_quintic::<KoalaBear5Config>();
+        assert_eq!(BabyBear5::TWO_ADICITY, 27);
+        assert_eq!(KoalaBear5::field_size_in_bits(), 155);
+
+        let value = BabyBear5::rand(&mut ark_std::test_rng());
+        let mut bytes = Vec::new();
+        value.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(
+            BabyBear5::deserialize_compressed(&bytes[..]).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_field64_6() {
+        let v = Field64_6::new(Field64_2::ZERO, Field64_2::ONE, Field64_2::ZERO);
+        assert_eq!(v.pow([3]), Field64_6::from(2_u64));
+        let mut rng = ark_std::test_rng();
+        let x = Field64_6::rand(&mut rng);
+        let mut expected = x;
+        for power in 1..6 {
+            expected = expected.pow(Field64::MODULUS);
+            let mut frobenius = x;
+            frobenius.frobenius_map_in_place(power);
+            assert_eq!(frobenius, expected);
+        }
+        assert_eq!(Field64_6::field_size_in_bits(), 384);
+    }
+
+    #[test]
+    fn test_mersenne31_complex() {
+        let i = Mersenne31Complex::new(Mersenne31::ZERO, Mersenne31::ONE);
+        assert_eq!(i.square(), -Mersenne31Complex::ONE);
+        assert_eq!(Mersenne31Complex::field_size_in_bits(), 62);
+
+        // The root of unity has order exactly 2^32, unlike in the base field.
+        let root = Mersenne31Complex::TWO_ADIC_ROOT_OF_UNITY;
+        assert_eq!(root.pow([1 << 31]), -Mersenne31Complex::ONE);
+        assert_eq!(root.pow([1 << 32]), Mersenne31Complex::ONE);
+        assert_eq!(Mersenne31::TWO_ADICITY, 1);
+        let omega = Mersenne31Complex::get_root_of_unity(1 << 20).unwrap();
+        assert_eq!(omega.pow([1 << 19]), -Mersenne31Complex::ONE);
+    }
+
     #[test]
     fn test_json_goldilocks_3() {
         let field_config = Type::<Field64_3>::new();
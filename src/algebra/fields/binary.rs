@@ -0,0 +1,740 @@
+//! Binary tower fields `𝔽_{2^k}` for `k = 8, 16, 32, 64, 128`.
+//!
+//! The tower is built from `𝔽_2` by the quadratic extensions
+//! `T_{i+1} = T_i[X_i] / (X_i^2 + X_{i-1} X_i + 1)` with `X_{-1} = 1` (Wiedemann's tower).
+//! An element `a + b X_i` of `T_{i+1}` is stored as the bits of `a` followed by the bits of `b`,
+//! so each level is a subfield of the larger ones by zero-extension, see
+//! [`Subfield`](crate::algebra::embedding::Subfield).
+//!
+//! The multiplicative groups have odd order, so there is no radix-2 NTT. Codewords are instead
+//! evaluated on 𝔽_2-linear subspaces by [`AdditiveNtt`](crate::algebra::ntt::AdditiveNtt).
+
+use std::{
+    fmt,
+    iter::{Product, Sum},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    sync::LazyLock,
+};
+
+use ark_ff::{
+    AdditiveGroup, BigInt, FftField, Field, Fp, Fp64, FpConfig, LegendreSymbol, One,
+    SqrtPrecomputation, Zero,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, Read, SerializationError, Valid,
+    Validate, Write,
+};
+use ark_std::rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use static_assertions::assert_impl_all;
+use zeroize::Zeroize;
+
+use crate::transcript::{Codec, Decoding, Encoding, NargDeserialize, VerificationResult};
+
+/// The prime field `𝔽_2` at the base of the binary towers.
+pub type BinaryField1 = Fp64<BinaryField1Config>;
+
+/// Arithmetic of [`BinaryField1`], storing the bit in the single limb.
+pub struct BinaryField1Config;
+
+impl FpConfig<1> for BinaryField1Config {
+    const MODULUS: BigInt<1> = BigInt([2]);
+    const GENERATOR: BinaryField1 = Fp(BigInt([1]), PhantomData);
+    const ZERO: BinaryField1 = Fp(BigInt([0]), PhantomData);
+    const ONE: BinaryField1 = Fp(BigInt([1]), PhantomData);
+    const TWO_ADICITY: u32 = 0;
+    const TWO_ADIC_ROOT_OF_UNITY: BinaryField1 = Self::ONE;
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<BinaryField1>> = None;
+
+    fn add_assign(a: &mut BinaryField1, b: &BinaryField1) {
+        a.0 .0[0] ^= b.0 .0[0];
+    }
+
+    fn sub_assign(a: &mut BinaryField1, b: &BinaryField1) {
+        a.0 .0[0] ^= b.0 .0[0];
+    }
+
+    fn double_in_place(a: &mut BinaryField1) {
+        a.0 .0[0] = 0;
+    }
+
+    fn neg_in_place(_a: &mut BinaryField1) {}
+
+    fn mul_assign(a: &mut BinaryField1, b: &BinaryField1) {
+        a.0 .0[0] &= b.0 .0[0];
+    }
+
+    fn sum_of_products<const T: usize>(
+        a: &[BinaryField1; T],
+        b: &[BinaryField1; T],
+    ) -> BinaryField1 {
+        let bit = a
+            .iter()
+            .zip(b)
+            .fold(0, |acc, (a, b)| acc ^ (a.0 .0[0] & b.0 .0[0]));
+        Fp(BigInt([bit]), PhantomData)
+    }
+
+    fn square_in_place(_a: &mut BinaryField1) {}
+
+    fn inverse(a: &BinaryField1) -> Option<BinaryField1> {
+        (a.0 .0[0] == 1).then_some(*a)
+    }
+
+    fn from_bigint(other: BigInt<1>) -> Option<BinaryField1> {
+        (other.0[0] < 2).then_some(Fp(other, PhantomData))
+    }
+
+    fn into_bigint(other: BinaryField1) -> BigInt<1> {
+        other.0
+    }
+}
+
+/// A level of the binary tower of at most 128 bits.
+pub trait BinaryTowerField: FftField<BasePrimeField = BinaryField1> {
+    /// The bits of the element in the tower basis.
+    fn to_u128(self) -> u128;
+
+    /// The element with the given bits in the tower basis, if they fit the level.
+    fn from_u128(bits: u128) -> Option<Self>;
+
+    /// Multiply by an element of a smaller level of the tower.
+    ///
+    /// Over the subfield the tower basis is a basis of monomials, so this multiplies each
+    /// subfield-sized chunk of the bits independently.
+    #[must_use]
+    fn mul_subfield<S: BinaryTowerField>(self, scalar: S) -> Self {
+        let width = S::extension_degree() as u32;
+        assert!(width <= Self::extension_degree() as u32);
+        let bits = self.to_u128();
+        let scalar = scalar.to_u128();
+        let mut result = 0;
+        for offset in (0..Self::extension_degree() as u32).step_by(width as usize) {
+            result |= mul((bits >> offset) & mask(width), scalar, width) << offset;
+        }
+        Self::from_u128(result).unwrap()
+    }
+}
+
+/// Multiplication table of the 8-bit level.
+static MUL_8: LazyLock<Vec<[u8; 256]>> = LazyLock::new(|| {
+    (0..256)
+        .map(|a| {
+            let mut row = [0; 256];
+            for (b, product) in row.iter_mut().enumerate() {
+                *product = mul_bits(a, b as u128, 8) as u8;
+            }
+            row
+        })
+        .collect()
+});
+
+/// Inverses in the 8-bit level, with zero mapped to zero.
+static INV_8: LazyLock<[u8; 256]> = LazyLock::new(|| {
+    let mut result = [0; 256];
+    for (a, inverse) in result.iter_mut().enumerate().skip(1) {
+        *inverse = MUL_8[a].iter().position(|&p| p == 1).unwrap() as u8;
+    }
+    result
+});
+
+/// The low `width` bits.
+const fn mask(width: u32) -> u128 {
+    if width == 128 {
+        u128::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// Multiply in the level of `width` bits, using tables for 8 bits and below.
+fn mul(a: u128, b: u128, width: u32) -> u128 {
+    if width <= 8 {
+        return u128::from(MUL_8[a as usize][b as usize]);
+    }
+    karatsuba(a, b, width, mul)
+}
+
+/// Multiply in the level of `width` bits without tables.
+fn mul_bits(a: u128, b: u128, width: u32) -> u128 {
+    if width == 1 {
+        return a & b;
+    }
+    karatsuba(a, b, width, mul_bits)
+}
+
+/// Multiply `a0 + a1 X` and `b0 + b1 X` using three multiplications in the level below.
+fn karatsuba(a: u128, b: u128, width: u32, mul: fn(u128, u128, u32) -> u128) -> u128 {
+    let half = width / 2;
+    let (a0, a1) = (a & mask(half), a >> half);
+    let (b0, b1) = (b & mask(half), b >> half);
+    let low = mul(a0, b0, half);
+    let high = mul(a1, b1, half);
+    let mid = mul(a0 ^ a1, b0 ^ b1, half) ^ low ^ high;
+    // Reduce by X^2 = X' X + 1 where X' generates the level below.
+    (low ^ high) | ((mid ^ mul_generator(high, half)) << half)
+}
+
+/// Multiply by the generator `X` of the level of `width` bits, where `X = 1` for `𝔽_2`.
+fn mul_generator(c: u128, width: u32) -> u128 {
+    if width == 1 {
+        return c;
+    }
+    let half = width / 2;
+    let (c0, c1) = (c & mask(half), c >> half);
+    // (c0 + c1 X) X = c1 + (c0 + c1 X') X
+    c1 | ((c0 ^ mul_generator(c1, half)) << half)
+}
+
+/// Invert a non-zero element in the level of `width` bits.
+fn inverse(a: u128, width: u32) -> u128 {
+    if width <= 8 {
+        return u128::from(INV_8[a as usize]);
+    }
+    let half = width / 2;
+    let (a0, a1) = (a & mask(half), a >> half);
+    // The conjugate of a0 + a1 X is (a0 + a1 X') + a1 X, and the norm lies in the level below.
+    let conjugate = a0 ^ mul_generator(a1, half);
+    let norm = mul(a0, conjugate, half) ^ mul(a1, a1, half);
+    let norm_inverse = inverse(norm, half);
+    mul(conjugate, norm_inverse, half) | (mul(a1, norm_inverse, half) << half)
+}
+
+macro_rules! binary_field {
+    ($(#[$attr:meta])* $name:ident, $int:ty, $bits:literal, $generator:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub struct $name($int);
+
+        impl $name {
+            /// The element with the given bits in the tower basis.
+            pub const fn new(bits: $int) -> Self {
+                Self(bits)
+            }
+
+            /// The bits of the element in the tower basis.
+            pub const fn to_bits(self) -> $int {
+                self.0
+            }
+
+            const fn xor(self, other: Self) -> Self {
+                Self(self.0 ^ other.0)
+            }
+
+            fn product(self, other: Self) -> Self {
+                Self(mul(self.0.into(), other.0.into(), $bits) as $int)
+            }
+
+            fn quotient(self, other: Self) -> Self {
+                self.product(other.inverse().expect("Division by zero"))
+            }
+        }
+
+        impl BinaryTowerField for $name {
+            fn to_u128(self) -> u128 {
+                self.0.into()
+            }
+
+            fn from_u128(bits: u128) -> Option<Self> {
+                <$int>::try_from(bits).ok().map(Self)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:#x}", self.0)
+            }
+        }
+
+        impl Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn is_one(&self) -> bool {
+                self.0 == 1
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self
+            }
+        }
+
+        binary_field_op!($name, Add, add, AddAssign, add_assign, xor);
+        binary_field_op!($name, Sub, sub, SubAssign, sub_assign, xor);
+        binary_field_op!($name, Mul, mul, MulAssign, mul_assign, product);
+        binary_field_op!($name, Div, div, DivAssign, div_assign, quotient);
+
+        impl Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Self::xor)
+            }
+        }
+
+        impl<'a> Sum<&'a Self> for $name {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, Self::product)
+            }
+        }
+
+        impl<'a> Product<&'a Self> for $name {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+
+        binary_field_from!($name, u128, u64, u32, u16, u8, i128, i64, i32, i16, i8);
+
+        impl From<bool> for $name {
+            fn from(value: bool) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        impl Distribution<$name> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name(rng.gen())
+            }
+        }
+
+        impl Valid for $name {
+            fn check(&self) -> Result<(), SerializationError> {
+                Ok(())
+            }
+        }
+
+        impl CanonicalSerialize for $name {
+            fn serialize_with_mode<W: Write>(
+                &self,
+                writer: W,
+                _compress: Compress,
+            ) -> Result<(), SerializationError> {
+                self.serialize_with_flags(writer, EmptyFlags)
+            }
+
+            fn serialized_size(&self, _compress: Compress) -> usize {
+                self.serialized_size_with_flags::<EmptyFlags>()
+            }
+        }
+
+        /// The bits in little-endian order, followed by a byte of flags if there are any.
+        impl CanonicalSerializeWithFlags for $name {
+            fn serialize_with_flags<W: Write, F: Flags>(
+                &self,
+                mut writer: W,
+                flags: F,
+            ) -> Result<(), SerializationError> {
+                writer.write_all(&self.0.to_le_bytes())?;
+                if F::BIT_SIZE > 0 {
+                    writer.write_all(&[flags.u8_bitmask()])?;
+                }
+                Ok(())
+            }
+
+            fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+                size_of::<$int>() + usize::from(F::BIT_SIZE > 0)
+            }
+        }
+
+        impl CanonicalDeserialize for $name {
+            fn deserialize_with_mode<R: Read>(
+                reader: R,
+                _compress: Compress,
+                _validate: Validate,
+            ) -> Result<Self, SerializationError> {
+                Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(value, _)| value)
+            }
+        }
+
+        impl CanonicalDeserializeWithFlags for $name {
+            fn deserialize_with_flags<R: Read, F: Flags>(
+                mut reader: R,
+            ) -> Result<(Self, F), SerializationError> {
+                let mut bytes = [0; size_of::<$int>()];
+                reader.read_exact(&mut bytes)?;
+                let mut flags = [0];
+                if F::BIT_SIZE > 0 {
+                    reader.read_exact(&mut flags)?;
+                }
+                let flags = F::from_u8(flags[0]).ok_or(SerializationError::UnexpectedFlags)?;
+                Ok((Self(<$int>::from_le_bytes(bytes)), flags))
+            }
+        }
+
+        impl AdditiveGroup for $name {
+            type Scalar = Self;
+
+            const ZERO: Self = Self(0);
+
+            fn double_in_place(&mut self) -> &mut Self {
+                *self = Self::ZERO;
+                self
+            }
+
+            fn neg_in_place(&mut self) -> &mut Self {
+                self
+            }
+        }
+
+        impl Field for $name {
+            type BasePrimeField = BinaryField1;
+
+            const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+
+            const ONE: Self = Self(1);
+
+            fn extension_degree() -> u64 {
+                $bits
+            }
+
+            fn to_base_prime_field_elements(&self) -> impl Iterator<Item = BinaryField1> {
+                let bits = self.0;
+                (0..$bits).map(move |i| BinaryField1::from((bits >> i) & 1 == 1))
+            }
+
+            fn from_base_prime_field_elems(
+                elems: impl IntoIterator<Item = BinaryField1>,
+            ) -> Option<Self> {
+                let mut bits: $int = 0;
+                let mut count = 0;
+                for elem in elems {
+                    if count == $bits {
+                        return None;
+                    }
+                    if elem.is_one() {
+                        bits |= 1 << count;
+                    }
+                    count += 1;
+                }
+                (count == $bits).then_some(Self(bits))
+            }
+
+            fn from_base_prime_field(elem: BinaryField1) -> Self {
+                Self(elem.is_one().into())
+            }
+
+            fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+                let value = bytes.get(..size_of::<$int>())?;
+                let flags = if F::BIT_SIZE > 0 {
+                    let mut byte = *bytes.get(size_of::<$int>())?;
+                    F::from_u8_remove_flags(&mut byte)?
+                } else {
+                    F::default()
+                };
+                Some((Self(<$int>::from_le_bytes(value.try_into().unwrap())), flags))
+            }
+
+            fn legendre(&self) -> LegendreSymbol {
+                // Squaring is a bijection in characteristic two.
+                if self.is_zero() {
+                    LegendreSymbol::Zero
+                } else {
+                    LegendreSymbol::QuadraticResidue
+                }
+            }
+
+            fn sqrt(&self) -> Option<Self> {
+                Some(self.frobenius_map($bits - 1))
+            }
+
+            fn square(&self) -> Self {
+                self.product(*self)
+            }
+
+            fn square_in_place(&mut self) -> &mut Self {
+                *self = self.square();
+                self
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                (!self.is_zero()).then(|| Self(inverse(self.0.into(), $bits) as $int))
+            }
+
+            fn inverse_in_place(&mut self) -> Option<&mut Self> {
+                *self = self.inverse()?;
+                Some(self)
+            }
+
+            fn frobenius_map_in_place(&mut self, power: usize) {
+                for _ in 0..power % $bits {
+                    self.square_in_place();
+                }
+            }
+
+            fn mul_by_base_prime_field(&self, elem: &BinaryField1) -> Self {
+                if elem.is_one() {
+                    *self
+                } else {
+                    Self::ZERO
+                }
+            }
+        }
+
+        /// The multiplicative group has odd order `2^k - 1`.
+        impl FftField for $name {
+            const GENERATOR: Self = Self($generator);
+            const TWO_ADICITY: u32 = 0;
+            const TWO_ADIC_ROOT_OF_UNITY: Self = Self(1);
+        }
+
+        impl Encoding<[u8]> for $name {
+            fn encode(&self) -> impl AsRef<[u8]> {
+                self.0.to_le_bytes()
+            }
+        }
+
+        /// Uniform, as every bit pattern is an element.
+        impl Decoding<[u8]> for $name {
+            type Repr = [u8; size_of::<$int>()];
+
+            fn decode(buf: Self::Repr) -> Self {
+                Self(<$int>::from_le_bytes(buf))
+            }
+        }
+
+        impl NargDeserialize for $name {
+            fn deserialize_from_narg(buf: &mut &[u8]) -> VerificationResult<Self> {
+                NargDeserialize::deserialize_from_narg(buf)
+                    .map(<$int>::from_le_bytes)
+                    .map(Self)
+            }
+        }
+
+        assert_impl_all!($name: Codec);
+    };
+}
+
+macro_rules! binary_field_op {
+    ($name:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $f:ident) => {
+        impl $op_assign<&Self> for $name {
+            fn $method_assign(&mut self, rhs: &Self) {
+                *self = self.$f(*rhs);
+            }
+        }
+
+        impl $op_assign<Self> for $name {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.$method_assign(&rhs);
+            }
+        }
+
+        impl $op_assign<&mut Self> for $name {
+            fn $method_assign(&mut self, rhs: &mut Self) {
+                self.$method_assign(&*rhs);
+            }
+        }
+
+        impl $op<Self> for $name {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$method_assign(&rhs);
+                self
+            }
+        }
+
+        impl $op<&Self> for $name {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &Self) -> Self {
+                self.$method_assign(rhs);
+                self
+            }
+        }
+
+        impl $op<&mut Self> for $name {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &mut Self) -> Self {
+                self.$method_assign(&*rhs);
+                self
+            }
+        }
+    };
+}
+
+/// Integers map to their residue modulo two.
+macro_rules! binary_field_from {
+    ($name:ident, $($int:ty),*) => {
+        $(
+            impl From<$int> for $name {
+                fn from(value: $int) -> Self {
+                    Self::from(value % 2 != 0)
+                }
+            }
+        )*
+    };
+}
+
+binary_field!(
+    /// The binary tower field `𝔽_{2^8}`.
+    BinaryField8,
+    u8,
+    8,
+    0x13
+);
+
+binary_field!(
+    /// The binary tower field `𝔽_{2^16}`.
+    BinaryField16,
+    u16,
+    16,
+    0x102
+);
+
+binary_field!(
+    /// The binary tower field `𝔽_{2^32}`.
+    BinaryField32,
+    u32,
+    32,
+    0x1_0005
+);
+
+binary_field!(
+    /// The binary tower field `𝔽_{2^64}`.
+    BinaryField64,
+    u64,
+    64,
+    0x1_0000_0004
+);
+
+binary_field!(
+    /// The binary tower field `𝔽_{2^128}`.
+    BinaryField128,
+    u128,
+    128,
+    0x1_0000_0000_0000_0005
+);
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::PrimeField;
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::fields::FieldWithSize;
+
+    fn check_field<F: BinaryTowerField>() {
+        let mut rng = ark_std::test_rng();
+        for _ in 0..100 {
+            let [a, b, c] = [(); 3].map(|()| F::rand(&mut rng));
+            assert_eq!(a * b, b * a);
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a + a, F::ZERO);
+            assert_eq!(a.square(), a * a);
+            if a != F::ZERO {
+                assert_eq!(a * a.inverse().unwrap(), F::ONE);
+                assert_eq!(b / a * a, b);
+            }
+            assert_eq!(a.sqrt().unwrap().square(), a);
+            assert_eq!(a.frobenius_map(1), a.square());
+            assert_eq!(a.frobenius_map(F::extension_degree() as usize), a);
+            assert_eq!(F::from_u128(a.to_u128()), Some(a));
+        }
+        assert_eq!(F::ONE.to_u128(), 1);
+        assert_eq!(F::from(3_u64), F::ONE);
+        assert_eq!(F::field_size_in_bits(), F::extension_degree() as usize);
+    }
+
+    /// Checks that `S` is a subfield of `F` and that the products agree.
+    fn check_subfield<S: BinaryTowerField, F: BinaryTowerField>() {
+        let mut rng = ark_std::test_rng();
+        let embed = |s: S| F::from_u128(s.to_u128()).unwrap();
+        for _ in 0..100 {
+            let [a, b] = [(); 2].map(|()| S::rand(&mut rng));
+            let x = F::rand(&mut rng);
+            assert_eq!(embed(a * b), embed(a) * embed(b));
+            assert_eq!(embed(a + b), embed(a) + embed(b));
+            assert_eq!(x.mul_subfield(a), x * embed(a));
+        }
+    }
+
+    fn check_serialization<F: BinaryTowerField>() {
+        let mut rng = ark_std::test_rng();
+        let a = F::rand(&mut rng);
+        let mut bytes = Vec::new();
+        a.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), F::extension_degree() as usize / 8);
+        assert_eq!(F::deserialize_compressed(bytes.as_slice()).unwrap(), a);
+        assert!(F::deserialize_compressed(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_binary_field1() {
+        let one = BinaryField1::ONE;
+        assert_eq!(one + one, BinaryField1::ZERO);
+        assert_eq!(one * one, one);
+        assert_eq!(BinaryField1::from(3_u64), one);
+        assert_eq!(
+            BinaryField1::from_le_bytes_mod_order(&[6]),
+            BinaryField1::ZERO
+        );
+        assert_eq!(BinaryField1::field_size_in_bits(), 1);
+    }
+
+    #[test]
+    fn test_fields() {
+        check_field::<BinaryField8>();
+        check_field::<BinaryField16>();
+        check_field::<BinaryField32>();
+        check_field::<BinaryField64>();
+        check_field::<BinaryField128>();
+    }
+
+    #[test]
+    fn test_subfields() {
+        check_subfield::<BinaryField8, BinaryField16>();
+        check_subfield::<BinaryField8, BinaryField128>();
+        check_subfield::<BinaryField16, BinaryField64>();
+        check_subfield::<BinaryField32, BinaryField128>();
+        check_subfield::<BinaryField64, BinaryField128>();
+    }
+
+    #[test]
+    fn test_serialization() {
+        check_serialization::<BinaryField8>();
+        check_serialization::<BinaryField32>();
+        check_serialization::<BinaryField128>();
+    }
+
+    #[test]
+    fn test_generators() {
+        // The multiplicative group of 𝔽_{2^8} is cyclic of order 255 = 3 · 5 · 17.
+        let generator = BinaryField8::GENERATOR;
+        assert_eq!(generator.pow([255]), BinaryField8::ONE);
+        for factor in [3, 5, 17] {
+            assert_ne!(generator.pow([255 / factor]), BinaryField8::ONE);
+        }
+    }
+}
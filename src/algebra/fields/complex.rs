@@ -0,0 +1,361 @@
+//! The complex extension of Mersenne31 with its full two-adic subgroup.
+//!
+//! The group order `p^2 - 1 = (p - 1)(p + 1) = 2^32 (2^30 - 1)` for `p = 2^31 - 1` has
+//! two-adicity 32, but arkworks' [`Fp2`] reports the two-adicity of the base field, which
+//! is one. [`Mersenne31Complex`] wraps [`Fp2`] for the arithmetic and provides its own
+//! [`FftField`] with the subgroup of order `2^32`.
+
+use std::{
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use ark_ff::{
+    AdditiveGroup, FftField, Field, Fp2, Fp2Config, LegendreSymbol, MontFp, One,
+    SqrtPrecomputation, Zero,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, Flags, Read, SerializationError, Valid, Validate, Write,
+};
+use ark_std::rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use zeroize::Zeroize;
+
+use super::Mersenne31;
+use crate::transcript::{Decoding, Encoding, NargDeserialize, VerificationResult};
+
+/// Complex extension of Mersenne31 `𝔽_p[i] / (i^2 + 1)`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Mersenne31Complex(Fp2<Mersenne31ComplexConfig>);
+
+pub struct Mersenne31ComplexConfig;
+
+impl Fp2Config for Mersenne31ComplexConfig {
+    type Fp = Mersenne31;
+
+    const NONRESIDUE: Self::Fp = MontFp!("-1");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Self::Fp] = &[
+        // (-1)^((p^0 - 1) / 2)
+        MontFp!("1"),
+        // (-1)^((p^1 - 1) / 2)
+        MontFp!("-1"),
+    ];
+}
+
+impl Mersenne31Complex {
+    /// The element `real + imaginary · i`.
+    pub const fn new(real: Mersenne31, imaginary: Mersenne31) -> Self {
+        Self(Fp2::new(real, imaginary))
+    }
+
+    pub const fn real(&self) -> Mersenne31 {
+        self.0.c0
+    }
+
+    pub const fn imaginary(&self) -> Mersenne31 {
+        self.0.c1
+    }
+}
+
+/// Implements `$op` and `$op_assign` for owned, borrowed and mutably borrowed operands.
+macro_rules! complex_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl $op_assign<&Self> for Mersenne31Complex {
+            fn $method_assign(&mut self, rhs: &Self) {
+                self.0.$method_assign(&rhs.0);
+            }
+        }
+
+        impl $op_assign<Self> for Mersenne31Complex {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.$method_assign(&rhs);
+            }
+        }
+
+        impl $op_assign<&mut Self> for Mersenne31Complex {
+            fn $method_assign(&mut self, rhs: &mut Self) {
+                self.$method_assign(&*rhs);
+            }
+        }
+
+        impl $op<Self> for Mersenne31Complex {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$method_assign(&rhs);
+                self
+            }
+        }
+
+        impl $op<&Self> for Mersenne31Complex {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &Self) -> Self {
+                self.$method_assign(rhs);
+                self
+            }
+        }
+
+        impl $op<&mut Self> for Mersenne31Complex {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &mut Self) -> Self {
+                self.$method_assign(&*rhs);
+                self
+            }
+        }
+    };
+}
+
+/// Integers embed as real numbers.
+macro_rules! complex_from {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Mersenne31Complex {
+                fn from(value: $int) -> Self {
+                    Self(Fp2::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl fmt::Display for Mersenne31Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mersenne31Complex({} + {} * i)", self.0.c0, self.0.c1)
+    }
+}
+
+impl Zero for Mersenne31Complex {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for Mersenne31Complex {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl Neg for Mersenne31Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+complex_op!(Add, add, AddAssign, add_assign);
+complex_op!(Sub, sub, SubAssign, sub_assign);
+complex_op!(Mul, mul, MulAssign, mul_assign);
+complex_op!(Div, div, DivAssign, div_assign);
+
+impl Sum for Mersenne31Complex {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Mersenne31Complex {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Mersenne31Complex {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Self> for Mersenne31Complex {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+complex_from!(bool, u128, u64, u32, u16, u8, i128, i64, i32, i16, i8);
+
+impl Zeroize for Mersenne31Complex {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Distribution<Mersenne31Complex> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mersenne31Complex {
+        Mersenne31Complex(rng.gen())
+    }
+}
+
+impl Valid for Mersenne31Complex {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl CanonicalSerialize for Mersenne31Complex {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.0.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0.serialized_size(compress)
+    }
+}
+
+impl CanonicalSerializeWithFlags for Mersenne31Complex {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        self.0.serialize_with_flags(writer, flags)
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        self.0.serialized_size_with_flags::<F>()
+    }
+}
+
+impl CanonicalDeserialize for Mersenne31Complex {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Fp2::deserialize_with_mode(reader, compress, validate).map(Self)
+    }
+}
+
+impl CanonicalDeserializeWithFlags for Mersenne31Complex {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        Fp2::deserialize_with_flags(reader).map(|(value, flags)| (Self(value), flags))
+    }
+}
+
+impl AdditiveGroup for Mersenne31Complex {
+    type Scalar = Self;
+
+    const ZERO: Self = Self(Fp2::ZERO);
+
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0.double_in_place();
+        self
+    }
+
+    fn neg_in_place(&mut self) -> &mut Self {
+        self.0.neg_in_place();
+        self
+    }
+}
+
+impl Field for Mersenne31Complex {
+    type BasePrimeField = Mersenne31;
+
+    /// Square roots are computed by [`Fp2::sqrt`].
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+
+    const ONE: Self = Self(Fp2::ONE);
+
+    fn extension_degree() -> u64 {
+        2
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Mersenne31> {
+        self.0.to_base_prime_field_elements()
+    }
+
+    fn from_base_prime_field_elems(elems: impl IntoIterator<Item = Mersenne31>) -> Option<Self> {
+        Fp2::from_base_prime_field_elems(elems).map(Self)
+    }
+
+    fn from_base_prime_field(elem: Mersenne31) -> Self {
+        Self(Fp2::from_base_prime_field(elem))
+    }
+
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        Fp2::from_random_bytes_with_flags(bytes).map(|(value, flags)| (Self(value), flags))
+    }
+
+    fn legendre(&self) -> LegendreSymbol {
+        self.0.legendre()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        self.0.sqrt().map(Self)
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        self.0.square_in_place();
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Self)
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        self.0.inverse_in_place()?;
+        Some(self)
+    }
+
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        self.0.frobenius_map_in_place(power);
+    }
+
+    fn mul_by_base_prime_field(&self, elem: &Mersenne31) -> Self {
+        Self(self.0.mul_by_base_prime_field(elem))
+    }
+}
+
+/// The generator `12 + i` and its power `(12 + i)^(2^30 - 1)` of order `2^32`.
+impl FftField for Mersenne31Complex {
+    const GENERATOR: Self = Self::new(MontFp!("12"), MontFp!("1"));
+    const TWO_ADICITY: u32 = 32;
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::new(MontFp!("1166849849"), MontFp!("1117296306"));
+}
+
+impl Encoding<[u8]> for Mersenne31Complex {
+    fn encode(&self) -> impl AsRef<[u8]> {
+        self.0.encode()
+    }
+}
+
+impl Decoding<[u8]> for Mersenne31Complex {
+    type Repr = <Fp2<Mersenne31ComplexConfig> as Decoding<[u8]>>::Repr;
+
+    fn decode(buf: Self::Repr) -> Self {
+        Self(Fp2::decode(buf))
+    }
+}
+
+impl NargDeserialize for Mersenne31Complex {
+    fn deserialize_from_narg(buf: &mut &[u8]) -> VerificationResult<Self> {
+        Fp2::deserialize_from_narg(buf).map(Self)
+    }
+}
@@ -0,0 +1,547 @@
+//! Quintic extensions `𝔽_p[X] / (X^5 - r(X))` of small prime fields.
+//!
+//! Arkworks only provides quadratic and cubic extensions and their towers, so degree five is
+//! implemented directly. Elements are stored as the coefficients of `1, X, …, X^4` and
+//! multiplied by schoolbook multiplication followed by reduction with `X^5 = r(X)`.
+//!
+//! The group order `p^5 - 1 = (p - 1)(p^4 + p^3 + p^2 + p + 1)` has the same two-adicity as
+//! `p - 1`, so the radix-2 subgroups are those of the base field.
+
+use std::{
+    fmt,
+    iter::{Product, Sum},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use ark_ff::{
+    AdditiveGroup, FftField, Field, LegendreSymbol, One, PrimeField, SqrtPrecomputation, Zero,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, Read, SerializationError, Valid,
+    Validate, Write,
+};
+use ark_std::{
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    UniformRand,
+};
+use derive_where::derive_where;
+use zeroize::Zeroize;
+
+use crate::transcript::{Decoding, Encoding, NargDeserialize, VerificationResult};
+
+/// Parameters of a [`QuinticExtension`].
+pub trait QuinticConfig: 'static + Send + Sync + Sized {
+    type Fp: PrimeField;
+
+    /// The coefficients of `r(X)` in the defining relation `X^5 = r(X)`.
+    ///
+    /// `X^5 - r(X)` must be irreducible over [`Self::Fp`].
+    const REDUCTION: [Self::Fp; 5];
+
+    /// The coefficients of a generator of the multiplicative group.
+    const GENERATOR: [Self::Fp; 5];
+
+    /// `(t - 1) / 2` in little-endian limbs, for `t` the odd part of `p^5 - 1`.
+    const TRACE_MINUS_ONE_DIV_TWO: &'static [u64];
+}
+
+/// An element `c_0 + c_1 X + c_2 X^2 + c_3 X^3 + c_4 X^4` of a quintic extension.
+#[derive_where(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct QuinticExtension<P: QuinticConfig> {
+    pub coeffs: [P::Fp; 5],
+    _config: PhantomData<P>,
+}
+
+/// Bytes of verifier randomness reduced to each coefficient by [`Decoding`].
+///
+/// This is 128 bits more than the base fields of at most 64 bits this is used with.
+const DECODING_BYTES: usize = 24;
+
+/// The verifier randomness from which [`Decoding`] samples a [`QuinticExtension`].
+#[derive(Clone, Copy, Debug)]
+pub struct DecodingBuffer([u8; 5 * DECODING_BYTES]);
+
+impl<P: QuinticConfig> QuinticExtension<P> {
+    pub const fn new(coeffs: [P::Fp; 5]) -> Self {
+        Self {
+            coeffs,
+            _config: PhantomData,
+        }
+    }
+
+    const fn constant(value: P::Fp) -> Self {
+        Self::new([value, P::Fp::ZERO, P::Fp::ZERO, P::Fp::ZERO, P::Fp::ZERO])
+    }
+
+    fn sum(self, other: &Self) -> Self {
+        let mut coeffs = self.coeffs;
+        for (c, o) in coeffs.iter_mut().zip(&other.coeffs) {
+            *c += o;
+        }
+        Self::new(coeffs)
+    }
+
+    fn difference(self, other: &Self) -> Self {
+        let mut coeffs = self.coeffs;
+        for (c, o) in coeffs.iter_mut().zip(&other.coeffs) {
+            *c -= o;
+        }
+        Self::new(coeffs)
+    }
+
+    fn product(self, other: &Self) -> Self {
+        let mut wide = [P::Fp::ZERO; 9];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                wide[i + j] += *a * b;
+            }
+        }
+        // Replace X^k = X^(k - 5) r(X) from the top, as the high terms of r may spill over.
+        for k in (5..9).rev() {
+            let high = wide[k];
+            for (i, r) in P::REDUCTION.iter().enumerate() {
+                if !r.is_zero() {
+                    wide[k - 5 + i] += high * r;
+                }
+            }
+        }
+        Self::new(wide[..5].try_into().unwrap())
+    }
+
+    fn quotient(self, other: &Self) -> Self {
+        self.product(&other.inverse().expect("Division by zero"))
+    }
+
+    /// Solves `self · y = 1` by Gaussian elimination on the matrix of multiplication by
+    /// `self`, returning its determinant (the norm of `self`) and `y` if it is non-zero.
+    fn solve(&self) -> (P::Fp, Option<Self>) {
+        // Column j of the matrix is self · X^j, with the right-hand side appended.
+        let mut rows = [[P::Fp::ZERO; 6]; 5];
+        let mut column = *self;
+        let x = Self::new([
+            P::Fp::ZERO,
+            P::Fp::ONE,
+            P::Fp::ZERO,
+            P::Fp::ZERO,
+            P::Fp::ZERO,
+        ]);
+        for j in 0..5 {
+            for (row, c) in rows.iter_mut().zip(&column.coeffs) {
+                row[j] = *c;
+            }
+            column = column.product(&x);
+        }
+        rows[0][5] = P::Fp::ONE;
+
+        let mut determinant = P::Fp::ONE;
+        for j in 0..5 {
+            let Some(pivot) = (j..5).find(|&i| !rows[i][j].is_zero()) else {
+                return (P::Fp::ZERO, None);
+            };
+            if pivot != j {
+                rows.swap(pivot, j);
+                determinant = -determinant;
+            }
+            determinant *= rows[j][j];
+            let inverse = rows[j][j].inverse().unwrap();
+            for c in &mut rows[j][j..] {
+                *c *= inverse;
+            }
+            let pivot_row = rows[j];
+            for (i, row) in rows.iter_mut().enumerate() {
+                let factor = row[j];
+                if i != j && !factor.is_zero() {
+                    for (c, p) in row[j..].iter_mut().zip(&pivot_row[j..]) {
+                        *c -= factor * p;
+                    }
+                }
+            }
+        }
+        let solution = Self::new(std::array::from_fn(|i| rows[i][5]));
+        (determinant, Some(solution))
+    }
+}
+
+/// Implements `$op` and `$op_assign` for owned, borrowed and mutably borrowed operands.
+macro_rules! quintic_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $f:ident) => {
+        impl<P: QuinticConfig> $op_assign<&Self> for QuinticExtension<P> {
+            fn $method_assign(&mut self, rhs: &Self) {
+                *self = self.$f(rhs);
+            }
+        }
+
+        impl<P: QuinticConfig> $op_assign<Self> for QuinticExtension<P> {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.$method_assign(&rhs);
+            }
+        }
+
+        impl<P: QuinticConfig> $op_assign<&mut Self> for QuinticExtension<P> {
+            fn $method_assign(&mut self, rhs: &mut Self) {
+                self.$method_assign(&*rhs);
+            }
+        }
+
+        impl<P: QuinticConfig> $op<Self> for QuinticExtension<P> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$method_assign(&rhs);
+                self
+            }
+        }
+
+        impl<P: QuinticConfig> $op<&Self> for QuinticExtension<P> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &Self) -> Self {
+                self.$method_assign(rhs);
+                self
+            }
+        }
+
+        impl<P: QuinticConfig> $op<&mut Self> for QuinticExtension<P> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &mut Self) -> Self {
+                self.$method_assign(&*rhs);
+                self
+            }
+        }
+    };
+}
+
+/// Integers embed as constants through the base field.
+macro_rules! quintic_from {
+    ($($int:ty),*) => {
+        $(
+            impl<P: QuinticConfig> From<$int> for QuinticExtension<P> {
+                fn from(value: $int) -> Self {
+                    Self::constant(P::Fp::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl<P: QuinticConfig> fmt::Display for QuinticExtension<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [c0, c1, c2, c3, c4] = &self.coeffs;
+        write!(
+            f,
+            "QuinticExtension({c0} + {c1} * X + {c2} * X^2 + {c3} * X^3 + {c4} * X^4)"
+        )
+    }
+}
+
+impl<P: QuinticConfig> Zero for QuinticExtension<P> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(Zero::is_zero)
+    }
+}
+
+impl<P: QuinticConfig> One for QuinticExtension<P> {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+
+impl<P: QuinticConfig> Neg for QuinticExtension<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(self.coeffs.map(|c| -c))
+    }
+}
+
+quintic_op!(Add, add, AddAssign, add_assign, sum);
+quintic_op!(Sub, sub, SubAssign, sub_assign, difference);
+quintic_op!(Mul, mul, MulAssign, mul_assign, product);
+quintic_op!(Div, div, DivAssign, div_assign, quotient);
+
+impl<P: QuinticConfig> Sum for QuinticExtension<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, P: QuinticConfig> Sum<&'a Self> for QuinticExtension<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<P: QuinticConfig> Product for QuinticExtension<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, P: QuinticConfig> Product<&'a Self> for QuinticExtension<P> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+quintic_from!(bool, u128, u64, u32, u16, u8, i128, i64, i32, i16, i8);
+
+impl<P: QuinticConfig> Zeroize for QuinticExtension<P> {
+    fn zeroize(&mut self) {
+        self.coeffs.zeroize();
+    }
+}
+
+impl<P: QuinticConfig> Distribution<QuinticExtension<P>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> QuinticExtension<P> {
+        QuinticExtension::new(std::array::from_fn(|_| P::Fp::rand(rng)))
+    }
+}
+
+impl<P: QuinticConfig> Valid for QuinticExtension<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.coeffs.iter().try_for_each(Valid::check)
+    }
+}
+
+impl<P: QuinticConfig> CanonicalSerialize for QuinticExtension<P> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+/// The coefficients in increasing degree, with the flags on the last one.
+impl<P: QuinticConfig> CanonicalSerializeWithFlags for QuinticExtension<P> {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        let (last, init) = self.coeffs.split_last().unwrap();
+        for c in init {
+            c.serialize_compressed(&mut writer)?;
+        }
+        last.serialize_with_flags(writer, flags)
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        let (last, init) = self.coeffs.split_last().unwrap();
+        init.iter()
+            .map(CanonicalSerialize::compressed_size)
+            .sum::<usize>()
+            + last.serialized_size_with_flags::<F>()
+    }
+}
+
+impl<P: QuinticConfig> CanonicalDeserialize for QuinticExtension<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        _compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let (value, EmptyFlags) = Self::deserialize_with_flags(reader)?;
+        if validate == Validate::Yes {
+            value.check()?;
+        }
+        Ok(value)
+    }
+}
+
+impl<P: QuinticConfig> CanonicalDeserializeWithFlags for QuinticExtension<P> {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        let mut coeffs = [P::Fp::ZERO; 5];
+        let (last, init) = coeffs.split_last_mut().unwrap();
+        for c in init {
+            *c = P::Fp::deserialize_compressed(&mut reader)?;
+        }
+        let (value, flags) = P::Fp::deserialize_with_flags(reader)?;
+        *last = value;
+        Ok((Self::new(coeffs), flags))
+    }
+}
+
+impl<P: QuinticConfig> AdditiveGroup for QuinticExtension<P> {
+    type Scalar = Self;
+
+    const ZERO: Self = Self::constant(P::Fp::ZERO);
+
+    fn double_in_place(&mut self) -> &mut Self {
+        for c in &mut self.coeffs {
+            c.double_in_place();
+        }
+        self
+    }
+
+    fn neg_in_place(&mut self) -> &mut Self {
+        for c in &mut self.coeffs {
+            c.neg_in_place();
+        }
+        self
+    }
+}
+
+impl<P: QuinticConfig> Field for QuinticExtension<P> {
+    type BasePrimeField = P::Fp;
+
+    /// Any primitive `2^s`-th root of unity is a non-residue, and the base field has one.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> =
+        Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: P::Fp::TWO_ADICITY,
+            quadratic_nonresidue_to_trace: Self::constant(P::Fp::TWO_ADIC_ROOT_OF_UNITY),
+            trace_of_modulus_minus_one_div_two: P::TRACE_MINUS_ONE_DIV_TWO,
+        });
+
+    const ONE: Self = Self::constant(P::Fp::ONE);
+
+    fn extension_degree() -> u64 {
+        5
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = P::Fp> {
+        self.coeffs.into_iter()
+    }
+
+    fn from_base_prime_field_elems(elems: impl IntoIterator<Item = P::Fp>) -> Option<Self> {
+        let mut elems = elems.into_iter();
+        let mut coeffs = [P::Fp::ZERO; 5];
+        for c in &mut coeffs {
+            *c = elems.next()?;
+        }
+        elems.next().is_none().then_some(Self::new(coeffs))
+    }
+
+    fn from_base_prime_field(elem: P::Fp) -> Self {
+        Self::constant(elem)
+    }
+
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let chunk = bytes.len() / 5;
+        let mut coeffs = [P::Fp::ZERO; 5];
+        for (c, bytes) in coeffs.iter_mut().zip(bytes.chunks_exact(chunk)).take(4) {
+            *c = P::Fp::from_random_bytes(bytes)?;
+        }
+        let (last, flags) = P::Fp::from_random_bytes_with_flags(&bytes[4 * chunk..])?;
+        coeffs[4] = last;
+        Some((Self::new(coeffs), flags))
+    }
+
+    /// An element is a square iff its norm is, as `(p^5 - 1) / 2` is the product of
+    /// `(p^5 - 1) / (p - 1)` and `(p - 1) / 2`.
+    fn legendre(&self) -> LegendreSymbol {
+        self.solve().0.legendre()
+    }
+
+    fn square(&self) -> Self {
+        self.product(self)
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = self.square();
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.solve().1
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        *self = self.inverse()?;
+        Some(self)
+    }
+
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        for _ in 0..power % 5 {
+            *self = self.pow(P::Fp::MODULUS);
+        }
+    }
+
+    fn mul_by_base_prime_field(&self, elem: &P::Fp) -> Self {
+        Self::new(self.coeffs.map(|c| c * elem))
+    }
+}
+
+impl<P: QuinticConfig> FftField for QuinticExtension<P> {
+    const GENERATOR: Self = Self::new(P::GENERATOR);
+    const TWO_ADICITY: u32 = P::Fp::TWO_ADICITY;
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::constant(P::Fp::TWO_ADIC_ROOT_OF_UNITY);
+}
+
+impl Default for DecodingBuffer {
+    fn default() -> Self {
+        Self([0; 5 * DECODING_BYTES])
+    }
+}
+
+impl AsRef<[u8]> for DecodingBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for DecodingBuffer {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// The encodings of the coefficients in increasing degree.
+impl<P: QuinticConfig> Encoding<[u8]> for QuinticExtension<P>
+where
+    P::Fp: Encoding<[u8]>,
+{
+    fn encode(&self) -> impl AsRef<[u8]> {
+        let mut bytes = Vec::new();
+        for c in &self.coeffs {
+            bytes.extend_from_slice(c.encode().as_ref());
+        }
+        bytes
+    }
+}
+
+/// Each coefficient is reduced from [`DECODING_BYTES`] little-endian bytes.
+impl<P: QuinticConfig> Decoding<[u8]> for QuinticExtension<P> {
+    type Repr = DecodingBuffer;
+
+    fn decode(buf: Self::Repr) -> Self {
+        let mut chunks = buf.0.chunks_exact(DECODING_BYTES);
+        Self::new(std::array::from_fn(|_| {
+            P::Fp::from_le_bytes_mod_order(chunks.next().unwrap())
+        }))
+    }
+}
+
+impl<P: QuinticConfig> NargDeserialize for QuinticExtension<P>
+where
+    P::Fp: NargDeserialize,
+{
+    fn deserialize_from_narg(buf: &mut &[u8]) -> VerificationResult<Self> {
+        let mut coeffs = [P::Fp::ZERO; 5];
+        for c in &mut coeffs {
+            *c = P::Fp::deserialize_from_narg(buf)?;
+        }
+        Ok(Self::new(coeffs))
+    }
+}
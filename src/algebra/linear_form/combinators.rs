@@ -0,0 +1,267 @@
+//! Linear forms built from other linear forms.
+
+use ark_ff::Field;
+
+use super::{Evaluate, LinearForm};
+use crate::algebra::{Embedding, MultilinearPoint};
+
+/// Sum of linear forms of the same size.
+///
+/// Deferred if any of the terms is.
+pub struct Sum<L> {
+    pub size: usize,
+    pub terms: Vec<L>,
+}
+
+/// A linear form multiplied by a constant.
+pub struct Scaled<F, L> {
+    pub scalar: F,
+    pub inner: L,
+}
+
+/// A linear form applied to the aligned sub-cube `offset..offset + inner.size()`.
+///
+/// The size of `inner` must be a power of two dividing `offset`, such that the sub-cube is
+/// obtained by fixing the most significant variables.
+pub struct Restrict<L> {
+    pub size: usize,
+    pub offset: usize,
+    pub inner: L,
+}
+
+/// A linear form applied to the first `inner.size()` entries of a larger vector.
+///
+/// Unlike [`Restrict`] the size of `inner` need not be a power of two.
+pub struct Embed<L> {
+    pub size: usize,
+    pub inner: L,
+}
+
+impl<L> Sum<L> {
+    pub fn new<F: Field>(size: usize, terms: Vec<L>) -> Self
+    where
+        L: LinearForm<F>,
+    {
+        assert!(terms.iter().all(|term| term.size() == size));
+        Self { size, terms }
+    }
+}
+
+impl<F, L> Scaled<F, L> {
+    pub const fn new(scalar: F, inner: L) -> Self {
+        Self { scalar, inner }
+    }
+}
+
+impl<L> Restrict<L> {
+    pub fn new<F: Field>(size: usize, offset: usize, inner: L) -> Self
+    where
+        L: LinearForm<F>,
+    {
+        let inner_size = inner.size();
+        assert!(inner_size.is_power_of_two());
+        assert_eq!(offset % inner_size, 0);
+        assert!(offset + inner_size <= size);
+        Self {
+            size,
+            offset,
+            inner,
+        }
+    }
+}
+
+impl<L> Embed<L> {
+    pub fn new<F: Field>(size: usize, inner: L) -> Self
+    where
+        L: LinearForm<F>,
+    {
+        assert!(inner.size() <= size);
+        Self { size, inner }
+    }
+}
+
+impl<F: Field, L: LinearForm<F>> LinearForm<F> for Sum<L> {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn deferred(&self) -> bool {
+        self.terms.iter().any(|term| term.deferred())
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        self.terms.iter().map(|term| term.mle_evaluate(point)).sum()
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        for term in &self.terms {
+            term.accumulate(accumulator, scalar);
+        }
+    }
+}
+
+impl<F: Field, L: LinearForm<F>> LinearForm<F> for Scaled<F, L> {
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn deferred(&self) -> bool {
+        self.inner.deferred()
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        self.scalar * self.inner.mle_evaluate(point)
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        self.inner.accumulate(accumulator, scalar * self.scalar);
+    }
+}
+
+impl<F: Field, L: LinearForm<F>> LinearForm<F> for Restrict<L> {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn deferred(&self) -> bool {
+        self.inner.deferred()
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        let inner_size = self.inner.size();
+        let (high, low) = point.split_at(point.len() - inner_size.trailing_zeros() as usize);
+        let block = MultilinearPoint(high.to_vec()).eq_poly(self.offset / inner_size);
+        block * self.inner.mle_evaluate(low)
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        assert_eq!(accumulator.len(), self.size);
+        let range = self.offset..self.offset + self.inner.size();
+        self.inner.accumulate(&mut accumulator[range], scalar);
+    }
+}
+
+impl<F: Field, L: LinearForm<F>> LinearForm<F> for Embed<L> {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn deferred(&self) -> bool {
+        self.inner.deferred()
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        let inner_variables = self.inner.size().next_power_of_two().trailing_zeros() as usize;
+        let (high, low) = point.split_at(point.len() - inner_variables);
+        let padding = high.iter().map(|&x| F::ONE - x).product::<F>();
+        padding * self.inner.mle_evaluate(low)
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        assert_eq!(accumulator.len(), self.size);
+        let inner_size = self.inner.size();
+        self.inner
+            .accumulate(&mut accumulator[..inner_size], scalar);
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M>> Evaluate<M> for Sum<L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        assert_eq!(vector.len(), self.size);
+        self.terms
+            .iter()
+            .map(|term| term.evaluate(embedding, vector))
+            .sum()
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M>> Evaluate<M> for Scaled<M::Target, L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        self.scalar * self.inner.evaluate(embedding, vector)
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M>> Evaluate<M> for Restrict<L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        assert_eq!(vector.len(), self.size);
+        let range = self.offset..self.offset + self.inner.size();
+        self.inner.evaluate(embedding, &vector[range])
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M>> Evaluate<M> for Embed<L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        assert_eq!(vector.len(), self.size);
+        self.inner.evaluate(embedding, &vector[..self.inner.size()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::{
+        embedding::Basefield,
+        fields::{Field64, Field64_2},
+        linear_form::{Covector, MultilinearExtension, SparseCovector, UnivariateEvaluation},
+    };
+
+    type F = Field64;
+    type EF = Field64_2;
+    type M = Basefield<EF>;
+
+    fn check(form: &dyn Evaluate<M>, num_variables: usize) {
+        let mut rng = ark_std::test_rng();
+        assert_eq!(form.size(), 1 << num_variables);
+        let dense = Covector::from(form as &dyn LinearForm<EF>);
+
+        let point = (0..num_variables)
+            .map(|_| EF::rand(&mut rng))
+            .collect::<Vec<_>>();
+        assert_eq!(form.mle_evaluate(&point), dense.mle_evaluate(&point));
+
+        let vector = (0..form.size())
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let embedding = M::new();
+        assert_eq!(
+            form.evaluate(&embedding, &vector),
+            dense.evaluate(&embedding, &vector)
+        );
+    }
+
+    fn random_covector(size: usize) -> Covector<EF> {
+        let mut rng = ark_std::test_rng();
+        Covector::new((0..size).map(|_| EF::rand(&mut rng)).collect())
+    }
+
+    #[test]
+    fn test_matches_covector() {
+        let mut rng = ark_std::test_rng();
+        let point = (0..3).map(|_| EF::rand(&mut rng)).collect::<Vec<_>>();
+
+        let terms: Vec<Box<dyn Evaluate<M>>> = vec![
+            Box::new(random_covector(8)),
+            Box::new(MultilinearExtension::new(point.clone())),
+            Box::new(UnivariateEvaluation::new(EF::rand(&mut rng), 8)),
+        ];
+        check(&Sum::new(8, terms), 3);
+        check(&Sum::<Covector<EF>>::new(8, Vec::new()), 3);
+        check(&Scaled::new(EF::rand(&mut rng), random_covector(16)), 4);
+        for offset in [0, 8, 24] {
+            check(
+                &Restrict::new(32, offset, MultilinearExtension::new(point.clone())),
+                5,
+            );
+        }
+        check(&Restrict::new(16, 12, random_covector(4)), 4);
+        let entries = vec![(0, EF::rand(&mut rng)), (4, EF::rand(&mut rng))];
+        check(&Embed::new(16, SparseCovector::new(5, entries)), 4);
+        check(
+            &Embed::new(16, UnivariateEvaluation::new(EF::rand(&mut rng), 4)),
+            4,
+        );
+        check(&Embed::new(8, MultilinearExtension::new(point)), 3);
+    }
+}
@@ -1,7 +1,12 @@
 //! Linear Forms that commited vectors can be openened against.
 
+mod combinators;
 mod covector;
 mod multilinear_extension;
+mod registry;
+mod shift;
+mod sparse_covector;
+mod tensor_product;
 mod univariate_evaluation;
 
 use std::any::Any;
@@ -10,7 +15,13 @@ use ark_ff::Field;
 use static_assertions::assert_obj_safe;
 
 pub use self::{
-    covector::Covector, multilinear_extension::MultilinearExtension,
+    combinators::{Embed, Restrict, Scaled, Sum},
+    covector::Covector,
+    multilinear_extension::MultilinearExtension,
+    registry::{kind_id, LinearFormKind, LinearForms, SerializableLinearForm, LINEAR_FORMS},
+    shift::{Rotated, Shifted},
+    sparse_covector::SparseCovector,
+    tensor_product::TensorProduct,
     univariate_evaluation::UnivariateEvaluation,
 };
 use crate::algebra::{
@@ -32,10 +43,10 @@ pub trait LinearForm<F: Field>: Any {
     /// Indicate if the verifier should evaluate this directly or defer it to the caller.
     ///
     /// If this returns `true`, the verifier will not call [`LinearForm::mle_evaluate`] but
-    /// instead [`crate::protocols::whir::Config::verify`]
-    /// will return `(point, value)` pairs and it becomes **the callers responsibility** to verify
-    /// `self.mle_evaluate(point) == value`. This allows the verifier to use more efficient means
-    /// than direct evaluation (e.g. the Spartan Spark protocol).
+    /// instead [`crate::protocols::whir::Config::verify`] will return
+    /// [`crate::protocols::whir::DeferredClaims`] and it becomes **the callers responsibility**
+    /// to verify `self.mle_evaluate(point) == value`. This allows the verifier to use more
+    /// efficient means than direct evaluation (e.g. the Spartan Spark protocol).
     fn deferred(&self) -> bool;
 
     /// Evaluate the linear form as a multi-linear extension in a random point.
@@ -71,6 +82,30 @@ pub trait Evaluate<M: Embedding>: LinearForm<M::Target> {
     fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target;
 }
 
+impl<F: Field, L: LinearForm<F> + ?Sized> LinearForm<F> for Box<L> {
+    fn size(&self) -> usize {
+        (**self).size()
+    }
+
+    fn deferred(&self) -> bool {
+        (**self).deferred()
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        (**self).mle_evaluate(point)
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        (**self).accumulate(accumulator, scalar);
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M> + ?Sized> Evaluate<M> for Box<L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        (**self).evaluate(embedding, vector)
+    }
+}
+
 assert_obj_safe!(LinearForm<fields::Field64>);
 
 assert_obj_safe!(Evaluate<embedding::Basefield<fields::Field64_2>>);
@@ -13,6 +13,10 @@ use crate::{
 /// $bits: ℕ → {0,1}^k$ is the little-endian binary decomposition, then this linear form will
 /// evaluate to $f(x)$ for some fixed point $x ∈ 𝔽^k$.
 ///
+/// This holds independent of the [`Basis`](crate::algebra::Basis) the vector is committed in.
+/// When committed as evaluations $f$ is the committed polynomial, when committed as
+/// coefficients the committed polynomial has the coefficients $v$ instead.
+///
 pub struct MultilinearExtension<F: Field> {
     pub point: Vec<F>,
 }
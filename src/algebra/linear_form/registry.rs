@@ -0,0 +1,253 @@
+//! Registry of serializable linear form kinds.
+//!
+//! Linear forms are serialized as a kind identifier with the parameters of the form. The
+//! built-in forms are always known, other kinds can be registered per field in
+//! [`LINEAR_FORMS`].
+
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    collections::HashMap,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use super::{
+    Covector, LinearForm, MultilinearExtension, SparseCovector, TensorProduct, UnivariateEvaluation,
+};
+use crate::{algebra::Basis, engines::EngineId};
+
+pub static LINEAR_FORMS: LazyLock<LinearForms> = LazyLock::new(LinearForms::new);
+
+const MULTILINEAR_EXTENSION: &str = "multilinear_extension";
+const UNIVARIATE_EVALUATION: &str = "univariate_evaluation";
+const COVECTOR: &str = "covector";
+const SPARSE_COVECTOR: &str = "sparse_covector";
+const TENSOR_PRODUCT: &str = "tensor_product";
+const BUILTINS: [&str; 5] = [
+    MULTILINEAR_EXTENSION,
+    UNIVARIATE_EVALUATION,
+    COVECTOR,
+    SPARSE_COVECTOR,
+    TENSOR_PRODUCT,
+];
+
+/// A [`LinearForm`] that can be serialized as its kind and parameters.
+pub trait SerializableLinearForm<F: Field>: LinearForm<F> {
+    /// Identifier of the [`LinearFormKind`] that deserializes this form.
+    fn kind(&self) -> EngineId;
+
+    /// Serialize the parameters of the form.
+    fn serialize_parameters(&self) -> Vec<u8>;
+}
+
+/// Deserializer for one kind of [`SerializableLinearForm`].
+pub trait LinearFormKind<F: Field>: Send + Sync {
+    /// Unique name of the kind.
+    fn name(&self) -> Cow<'_, str>;
+
+    /// Identifier of the kind, derived from the name.
+    fn kind(&self) -> EngineId {
+        kind_id(&self.name())
+    }
+
+    /// Deserialize a form from its parameters, returning `None` if they are invalid.
+    fn deserialize_parameters(
+        &self,
+        parameters: &[u8],
+    ) -> Option<Box<dyn SerializableLinearForm<F>>>;
+}
+
+/// Registered [`LinearFormKind`]s by field and kind identifier.
+#[derive(Default)]
+pub struct LinearForms(RwLock<HashMap<(TypeId, EngineId), Box<dyn Any + Send + Sync>>>);
+
+/// The built-in linear forms, available for every field.
+struct Builtin(&'static str);
+
+/// The [`EngineId`] of a linear form kind with the given name.
+pub fn kind_id(name: &str) -> EngineId {
+    use digest::Digest;
+    use sha3::Sha3_256;
+
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"whir::linear_form");
+    hasher.update(name.as_bytes());
+    let hash: [u8; 32] = hasher.finalize().into();
+    hash.into()
+}
+
+impl LinearForms {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a kind for field `F`, replacing any previous kind with the same identifier.
+    pub fn register<F: Field>(&self, kind: Arc<dyn LinearFormKind<F>>) {
+        self.0
+            .write()
+            .expect("Lock poisoned")
+            .insert((TypeId::of::<F>(), kind.kind()), Box::new(kind));
+    }
+
+    pub fn contains<F: Field>(&self, kind: EngineId) -> bool {
+        self.retrieve::<F>(kind).is_some()
+    }
+
+    /// Retrieve a registered kind, falling back to the built-in forms.
+    pub fn retrieve<F: Field>(&self, kind: EngineId) -> Option<Arc<dyn LinearFormKind<F>>> {
+        let registered = self
+            .0
+            .read()
+            .expect("Lock poisoned")
+            .get(&(TypeId::of::<F>(), kind))
+            .and_then(|kind| kind.downcast_ref::<Arc<dyn LinearFormKind<F>>>())
+            .cloned();
+        registered.or_else(|| {
+            BUILTINS
+                .into_iter()
+                .find(|&name| kind_id(name) == kind)
+                .map(|name| Arc::new(Builtin(name)) as Arc<dyn LinearFormKind<F>>)
+        })
+    }
+
+    pub fn deserialize<F: Field>(
+        &self,
+        kind: EngineId,
+        parameters: &[u8],
+    ) -> Option<Box<dyn SerializableLinearForm<F>>> {
+        self.retrieve::<F>(kind)?.deserialize_parameters(parameters)
+    }
+}
+
+fn to_bytes<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut buf)
+        .expect("Serialization to Vec can not fail");
+    buf
+}
+
+fn from_bytes<T: CanonicalDeserialize>(mut bytes: &[u8]) -> Option<T> {
+    let value = T::deserialize_compressed(&mut bytes).ok()?;
+    bytes.is_empty().then_some(value)
+}
+
+impl<F: Field> LinearFormKind<F> for Builtin {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.0)
+    }
+
+    fn deserialize_parameters(
+        &self,
+        parameters: &[u8],
+    ) -> Option<Box<dyn SerializableLinearForm<F>>> {
+        Some(match self.0 {
+            MULTILINEAR_EXTENSION => {
+                let point: Vec<F> = from_bytes(parameters)?;
+                // The size `1 << point.len()` must fit in a `usize`.
+                if point.len() >= usize::BITS as usize {
+                    return None;
+                }
+                Box::new(MultilinearExtension::new(point))
+            }
+            UNIVARIATE_EVALUATION => {
+                let (size, point): (usize, F) = from_bytes(parameters)?;
+                // WHIR vectors have `1 << num_variables` entries.
+                if !size.is_power_of_two() {
+                    return None;
+                }
+                Box::new(UnivariateEvaluation::new(point, size))
+            }
+            COVECTOR => {
+                let (deferred, vector): (bool, Vec<F>) = from_bytes(parameters)?;
+                Box::new(Covector { deferred, vector })
+            }
+            SPARSE_COVECTOR => {
+                let (size, deferred, entries): (usize, bool, Vec<(usize, F)>) =
+                    from_bytes(parameters)?;
+                // Same rule as `SparseCovector::new`.
+                if entries.iter().any(|&(index, _)| index >= size) {
+                    return None;
+                }
+                Box::new(SparseCovector {
+                    size,
+                    deferred,
+                    entries,
+                })
+            }
+            TENSOR_PRODUCT => {
+                let factors: Vec<Vec<F>> = from_bytes(parameters)?;
+                if !factors.iter().all(|factor| factor.len().is_power_of_two()) {
+                    return None;
+                }
+                // The total size must fit in a `usize`.
+                factors
+                    .iter()
+                    .try_fold(1_usize, |size, factor| size.checked_mul(factor.len()))?;
+                Box::new(TensorProduct { factors })
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl<F: Field> SerializableLinearForm<F> for MultilinearExtension<F> {
+    fn kind(&self) -> EngineId {
+        kind_id(MULTILINEAR_EXTENSION)
+    }
+
+    fn serialize_parameters(&self) -> Vec<u8> {
+        to_bytes(&self.point)
+    }
+}
+
+/// In the [`Basis::Evaluations`] basis this is serialized as the equivalent
+/// [`MultilinearExtension`].
+impl<F: Field> SerializableLinearForm<F> for UnivariateEvaluation<F> {
+    fn kind(&self) -> EngineId {
+        match self.basis {
+            Basis::Coefficients => kind_id(UNIVARIATE_EVALUATION),
+            Basis::Evaluations => kind_id(MULTILINEAR_EXTENSION),
+        }
+    }
+
+    fn serialize_parameters(&self) -> Vec<u8> {
+        match self.basis {
+            Basis::Coefficients => to_bytes(&(self.size, self.point)),
+            Basis::Evaluations => to_bytes(&self.expanded_point().0),
+        }
+    }
+}
+
+impl<F: Field> SerializableLinearForm<F> for Covector<F> {
+    fn kind(&self) -> EngineId {
+        kind_id(COVECTOR)
+    }
+
+    fn serialize_parameters(&self) -> Vec<u8> {
+        to_bytes(&(self.deferred, self.vector.as_slice()))
+    }
+}
+
+impl<F: Field> SerializableLinearForm<F> for SparseCovector<F> {
+    fn kind(&self) -> EngineId {
+        kind_id(SPARSE_COVECTOR)
+    }
+
+    fn serialize_parameters(&self) -> Vec<u8> {
+        to_bytes(&(self.size, self.deferred, self.entries.as_slice()))
+    }
+}
+
+impl<F: Field> SerializableLinearForm<F> for TensorProduct<F> {
+    fn kind(&self) -> EngineId {
+        kind_id(TENSOR_PRODUCT)
+    }
+
+    fn serialize_parameters(&self) -> Vec<u8> {
+        to_bytes(&self.factors)
+    }
+}
@@ -0,0 +1,190 @@
+use ark_ff::Field;
+
+use super::{Evaluate, LinearForm};
+use crate::algebra::Embedding;
+
+/// The linear form $v ↦ ⟨w, shift(v)⟩$ where $shift(v)_i = v_(i+1)$ and $shift(v)_(n-1) = 0$.
+///
+/// Here $w$ is the covector of `inner`. The multilinear extension is computed from
+/// $⌈log_2 n⌉$ evaluations of `inner` using the closed form of the successor relation,
+/// so this is succinct whenever `inner` is.
+pub struct Shifted<L> {
+    pub inner: L,
+}
+
+/// The linear form $v ↦ ⟨w, rot(v)⟩$ where $rot(v)_i = v_((i+1) mod n)$.
+///
+/// Same as [`Shifted`] but wrapping around, at the cost of one more evaluation of `inner`.
+pub struct Rotated<L> {
+    pub inner: L,
+}
+
+impl<L> Shifted<L> {
+    pub const fn new(inner: L) -> Self {
+        Self { inner }
+    }
+}
+
+impl<L> Rotated<L> {
+    pub const fn new(inner: L) -> Self {
+        Self { inner }
+    }
+}
+
+impl<F: Field, L: LinearForm<F>> LinearForm<F> for Shifted<L> {
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn deferred(&self) -> bool {
+        self.inner.deferred()
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        shifted_mle_evaluate(&self.inner, point)
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        shifted_accumulate(&self.inner, accumulator, scalar, false);
+    }
+}
+
+impl<F: Field, L: LinearForm<F>> LinearForm<F> for Rotated<L> {
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn deferred(&self) -> bool {
+        self.inner.deferred()
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        // The wrap-around term maps the last entry to the first.
+        let last = self.inner.mle_evaluate(&vec![F::ONE; point.len()]);
+        let first = point.iter().map(|&x| F::ONE - x).product::<F>();
+        shifted_mle_evaluate(&self.inner, point) + last * first
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        shifted_accumulate(&self.inner, accumulator, scalar, true);
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M>> Evaluate<M> for Shifted<L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        let mut shifted = vector[1..].to_vec();
+        shifted.push(M::Source::ZERO);
+        self.inner.evaluate(embedding, &shifted)
+    }
+}
+
+impl<M: Embedding, L: Evaluate<M>> Evaluate<M> for Rotated<L> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        let mut rotated = vector.to_vec();
+        rotated.rotate_left(1);
+        self.inner.evaluate(embedding, &rotated)
+    }
+}
+
+/// Multilinear extension of the transposed shift of `inner`'s covector $w$.
+///
+/// With $next(x, y) = 1$ iff $y = x + 1$ we need $∑_x w_x · next(x, r)$. In big-endian
+/// order $x + 1 = y$ iff for some position $k$ the lower bits of $x$ are one and of $y$
+/// are zero, $x_k = 0$, $y_k = 1$ and the higher bits agree. For fixed $k$ this is an
+/// equality polynomial in $x$, so each term is a scaled evaluation of `inner`.
+fn shifted_mle_evaluate<F: Field, L: LinearForm<F>>(inner: &L, point: &[F]) -> F {
+    assert_eq!(inner.size(), 1 << point.len());
+    let mut result = F::ZERO;
+    let mut shifted_point = point.to_vec();
+    // Product of (1 - r_j) over the positions after `k`.
+    let mut carry = F::ONE;
+    for k in (0..point.len()).rev() {
+        shifted_point[k] = F::ZERO;
+        result += carry * point[k] * inner.mle_evaluate(&shifted_point);
+        shifted_point[k] = F::ONE;
+        carry *= F::ONE - point[k];
+    }
+    result
+}
+
+fn shifted_accumulate<F: Field, L: LinearForm<F>>(
+    inner: &L,
+    accumulator: &mut [F],
+    scalar: F,
+    cyclic: bool,
+) {
+    assert_eq!(accumulator.len(), inner.size());
+    let mut covector = vec![F::ZERO; inner.size()];
+    inner.accumulate(&mut covector, scalar);
+    for (acc, &w) in accumulator[1..].iter_mut().zip(&covector) {
+        *acc += w;
+    }
+    if cyclic {
+        accumulator[0] += covector[covector.len() - 1];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::{
+        embedding::Basefield,
+        fields::{Field64, Field64_2},
+        linear_form::{Covector, MultilinearExtension},
+    };
+
+    fn check<L>(form: &L, num_variables: usize, cyclic: bool)
+    where
+        L: Evaluate<Basefield<Field64_2>>,
+    {
+        let mut rng = ark_std::test_rng();
+        let size = 1 << num_variables;
+        let dense = Covector::from(form as &dyn LinearForm<Field64_2>);
+
+        let point = (0..num_variables)
+            .map(|_| Field64_2::rand(&mut rng))
+            .collect::<Vec<_>>();
+        assert_eq!(form.mle_evaluate(&point), dense.mle_evaluate(&point));
+
+        // The evaluation agrees with the covector and with shifting explicitly.
+        let vector = (0..size)
+            .map(|_| Field64::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let embedding = Basefield::<Field64_2>::new();
+        let value = form.evaluate(&embedding, &vector);
+        assert_eq!(value, dense.evaluate(&embedding, &vector));
+        let expected =
+            MultilinearExtension::new(point.clone()).evaluate(&embedding, &shift(&vector, cyclic));
+        let inner = MultilinearExtension::new(point);
+        let wrapped: Box<dyn Evaluate<Basefield<Field64_2>>> = if cyclic {
+            Box::new(Rotated::new(inner))
+        } else {
+            Box::new(Shifted::new(inner))
+        };
+        assert_eq!(wrapped.evaluate(&embedding, &vector), expected);
+    }
+
+    fn shift(vector: &[Field64], cyclic: bool) -> Vec<Field64> {
+        let mut result = vector[1..].to_vec();
+        result.push(if cyclic { vector[0] } else { Field64::from(0) });
+        result
+    }
+
+    #[test]
+    fn test_matches_covector() {
+        let mut rng = ark_std::test_rng();
+        for num_variables in 0..6 {
+            let vector = (0..1 << num_variables)
+                .map(|_| Field64_2::rand(&mut rng))
+                .collect::<Vec<_>>();
+            check(
+                &Shifted::new(Covector::new(vector.clone())),
+                num_variables,
+                false,
+            );
+            check(&Rotated::new(Covector::new(vector)), num_variables, true);
+        }
+    }
+}
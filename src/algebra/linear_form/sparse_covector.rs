@@ -0,0 +1,122 @@
+use ark_ff::Field;
+
+use super::{Evaluate, LinearForm};
+use crate::algebra::Embedding;
+
+/// Linear form as a covector with few nonzero entries.
+///
+/// Stores `(index, value)` pairs. Indices may repeat, in which case the values
+/// are summed. Evaluation, [`LinearForm::accumulate`] and
+/// [`LinearForm::mle_evaluate`] cost $O(nnz)$, $O(nnz)$ and $O(nnz · log n)$
+/// respectively.
+pub struct SparseCovector<F: Field> {
+    pub size: usize,
+    pub deferred: bool,
+    pub entries: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparseCovector<F> {
+    pub fn new(size: usize, entries: Vec<(usize, F)>) -> Self {
+        assert!(entries.iter().all(|&(index, _)| index < size));
+        Self {
+            size,
+            deferred: false,
+            entries,
+        }
+    }
+
+    /// The linear form selecting the entry at `index`.
+    pub fn unit(size: usize, index: usize) -> Self {
+        Self::new(size, vec![(index, F::ONE)])
+    }
+}
+
+impl<F: Field> LinearForm<F> for SparseCovector<F> {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn deferred(&self) -> bool {
+        self.deferred
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        assert!(self.size <= 1 << point.len());
+        self.entries
+            .iter()
+            .map(|&(index, value)| {
+                // Big-endian: the last coordinate corresponds to the lowest bit.
+                point
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .fold(value, |acc, (bit, &x)| {
+                        if (index >> bit) & 1 == 1 {
+                            acc * x
+                        } else {
+                            acc * (F::ONE - x)
+                        }
+                    })
+            })
+            .sum()
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        assert_eq!(accumulator.len(), self.size);
+        for &(index, value) in &self.entries {
+            accumulator[index] += scalar * value;
+        }
+    }
+}
+
+impl<M: Embedding> Evaluate<M> for SparseCovector<M::Target> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        assert_eq!(self.size, vector.len());
+        self.entries
+            .iter()
+            .map(|&(index, value)| embedding.mixed_mul(value, vector[index]))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::{
+        embedding::Basefield,
+        fields::{Field64, Field64_2},
+        linear_form::Covector,
+    };
+
+    #[test]
+    fn test_matches_covector() {
+        let mut rng = ark_std::test_rng();
+        let num_variables = 5;
+        let size = 1 << num_variables;
+        let entries = vec![
+            (0, Field64_2::rand(&mut rng)),
+            (7, Field64_2::rand(&mut rng)),
+            (19, Field64_2::rand(&mut rng)),
+            (7, Field64_2::rand(&mut rng)),
+            (31, Field64_2::rand(&mut rng)),
+        ];
+        let sparse = SparseCovector::new(size, entries);
+        let dense = Covector::from(&sparse as &dyn LinearForm<Field64_2>);
+
+        let point = (0..num_variables)
+            .map(|_| Field64_2::rand(&mut rng))
+            .collect::<Vec<_>>();
+        assert_eq!(sparse.mle_evaluate(&point), dense.mle_evaluate(&point));
+
+        let vector = (0..size)
+            .map(|_| Field64::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let embedding = Basefield::<Field64_2>::new();
+        assert_eq!(
+            sparse.evaluate(&embedding, &vector),
+            dense.evaluate(&embedding, &vector)
+        );
+    }
+}
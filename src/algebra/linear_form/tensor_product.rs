@@ -0,0 +1,129 @@
+use ark_ff::Field;
+
+use super::{Evaluate, LinearForm};
+use crate::algebra::{mixed_dot, multilinear_extend, scalar_mul_add, tensor_product, Embedding};
+
+/// Linear form given by the covector $a_0 ⊗ a_1 ⊗ ⋯ ⊗ a_(k-1)$.
+///
+/// The factors have power-of-two lengths with $a_0$ the most significant, such that the
+/// multilinear extension factors as a product over consecutive ranges of the point. This makes
+/// [`LinearForm::mle_evaluate`] linear in the total size of the factors.
+pub struct TensorProduct<F: Field> {
+    pub factors: Vec<Vec<F>>,
+}
+
+impl<F: Field> TensorProduct<F> {
+    pub fn new(factors: Vec<Vec<F>>) -> Self {
+        assert!(factors.iter().all(|factor| factor.len().is_power_of_two()));
+        Self { factors }
+    }
+
+    /// Expand all but the last factor, scaled by `scalar`.
+    ///
+    /// Together with the last factor this streams the full tensor product
+    /// without materializing it.
+    fn expand_prefix(&self, scalar: F) -> (Vec<F>, &[F]) {
+        match self.factors.split_last() {
+            Some((last, init)) => {
+                let prefix = init
+                    .iter()
+                    .fold(vec![scalar], |acc, factor| tensor_product(&acc, factor));
+                (prefix, last)
+            }
+            None => (vec![scalar], &[]),
+        }
+    }
+}
+
+impl<F: Field> LinearForm<F> for TensorProduct<F> {
+    fn size(&self) -> usize {
+        self.factors.iter().map(Vec::len).product()
+    }
+
+    fn deferred(&self) -> bool {
+        false
+    }
+
+    fn mle_evaluate(&self, point: &[F]) -> F {
+        let mut point = point;
+        let mut result = F::ONE;
+        for factor in &self.factors {
+            let (head, tail) = point.split_at(factor.len().trailing_zeros() as usize);
+            result *= multilinear_extend(factor, head);
+            point = tail;
+        }
+        assert!(point.is_empty());
+        result
+    }
+
+    fn accumulate(&self, accumulator: &mut [F], scalar: F) {
+        assert_eq!(accumulator.len(), self.size());
+        let (prefix, last) = self.expand_prefix(scalar);
+        if last.is_empty() {
+            accumulator[0] += prefix[0];
+            return;
+        }
+        for (chunk, &weight) in accumulator.chunks_exact_mut(last.len()).zip(&prefix) {
+            scalar_mul_add(chunk, weight, last);
+        }
+    }
+}
+
+impl<M: Embedding> Evaluate<M> for TensorProduct<M::Target> {
+    fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
+        assert_eq!(vector.len(), self.size());
+        let (prefix, last) = self.expand_prefix(M::Target::ONE);
+        if last.is_empty() {
+            return embedding.map(vector[0]);
+        }
+        vector
+            .chunks_exact(last.len())
+            .zip(&prefix)
+            .map(|(chunk, &weight)| weight * mixed_dot(embedding, last, chunk))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::{
+        embedding::Basefield,
+        fields::{Field64, Field64_2},
+        linear_form::Covector,
+    };
+
+    #[test]
+    fn test_matches_covector() {
+        let mut rng = ark_std::test_rng();
+        for sizes in [vec![], vec![4], vec![2, 8], vec![4, 1, 2, 4]] {
+            let factors = sizes
+                .iter()
+                .map(|&size| {
+                    (0..size)
+                        .map(|_| Field64_2::rand(&mut rng))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let form = TensorProduct::new(factors);
+            let dense = Covector::from(&form as &dyn LinearForm<Field64_2>);
+            let num_variables = form.size().trailing_zeros() as usize;
+
+            let point = (0..num_variables)
+                .map(|_| Field64_2::rand(&mut rng))
+                .collect::<Vec<_>>();
+            assert_eq!(form.mle_evaluate(&point), dense.mle_evaluate(&point));
+
+            let vector = (0..form.size())
+                .map(|_| Field64::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let embedding = Basefield::<Field64_2>::new();
+            assert_eq!(
+                form.evaluate(&embedding, &vector),
+                dense.evaluate(&embedding, &vector)
+            );
+        }
+    }
+}
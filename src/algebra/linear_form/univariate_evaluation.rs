@@ -2,23 +2,46 @@ use ark_ff::Field;
 
 use super::LinearForm;
 use crate::algebra::{
-    embedding::Embedding, geometric_accumulate, linear_form::Evaluate, mixed_univariate_evaluate,
+    embedding::Embedding, eval_eq, geometric_accumulate, linear_form::Evaluate,
+    mixed_multilinear_extend, mixed_univariate_evaluate, Basis, MultilinearPoint,
 };
 
 /// Linear form to represent univariate polynomial evaluation.
 ///
-/// Given a vector $v ∈ 𝔽^n$ it computes $sum_i v_i · x^i$ for some fixed $x$.
+/// Given a vector $v ∈ 𝔽^n$ it computes $sum_i v_i · x^i$ for some fixed $x$. In the
+/// [`Basis::Evaluations`] basis it instead evaluates the polynomial with the monomial
+/// coefficients of $v$, which is the multilinear extension of $v$ in
+/// [`MultilinearPoint::expand_from_univariate`].
 pub struct UnivariateEvaluation<F: Field> {
     /// Univariate evaluation doesn't have an inherent size, so we need to store one.
     pub size: usize,
 
     /// The point $x ∈ 𝔽$ to evaluate on.
     pub point: F,
+
+    /// The basis the vector is given in.
+    pub basis: Basis,
 }
 
 impl<F: Field> UnivariateEvaluation<F> {
     pub const fn new(point: F, size: usize) -> Self {
-        Self { size, point }
+        Self {
+            size,
+            point,
+            basis: Basis::Coefficients,
+        }
+    }
+
+    /// Evaluate vectors given in `basis` instead.
+    pub const fn with_basis(mut self, basis: Basis) -> Self {
+        assert!(matches!(basis, Basis::Coefficients) || self.size.is_power_of_two());
+        self.basis = basis;
+        self
+    }
+
+    /// The multilinear point corresponding to [`Basis::Evaluations`].
+    pub fn expanded_point(&self) -> MultilinearPoint<F> {
+        MultilinearPoint::expand_from_univariate(self.point, self.size.ilog2() as usize)
     }
 
     /// Batched version of [`LinearForm::accumulate`] for many [`UnivariateEvaluation`]s.
@@ -28,12 +51,19 @@ impl<F: Field> UnivariateEvaluation<F> {
             return;
         };
         assert_eq!(accumulator.len(), size);
-        for evaluator in evaluators {
+        let mut points = Vec::with_capacity(evaluators.len());
+        let mut coefficient_scalars = Vec::with_capacity(evaluators.len());
+        for (evaluator, &scalar) in evaluators.iter().zip(scalars) {
             assert_eq!(evaluator.size, size);
+            match evaluator.basis {
+                Basis::Coefficients => {
+                    points.push(evaluator.point);
+                    coefficient_scalars.push(scalar);
+                }
+                Basis::Evaluations => evaluator.accumulate(accumulator, scalar),
+            }
         }
-        let points = evaluators.iter().map(|e| e.point).collect::<Vec<F>>();
-        let scalars = scalars.to_vec();
-        geometric_accumulate(accumulator, scalars, &points);
+        geometric_accumulate(accumulator, coefficient_scalars, &points);
     }
 }
 
@@ -47,12 +77,16 @@ impl<F: Field> LinearForm<F> for UnivariateEvaluation<F> {
     }
 
     fn mle_evaluate(&self, point: &[F]) -> F {
-        // Multilinear extension of (1, x, x^2, ..) = ⨂_i (1, x^2^i).
+        // Multilinear extension of (1, x, x^2, ..) = ⨂_i (1, x^2^i),
+        // and of eq(x^2^i, ·) = ⨂_i (1 - x^2^i, x^2^i).
         let mut x2i = self.point;
         let mut result = F::ONE;
         for &r in point.iter().rev() {
             // TODO: Why rev?
-            result *= (F::ONE - r) + r * x2i;
+            result *= match self.basis {
+                Basis::Coefficients => (F::ONE - r) + r * x2i,
+                Basis::Evaluations => (F::ONE - r) * (F::ONE - x2i) + r * x2i,
+            };
             x2i.square_in_place();
         }
         result
@@ -61,16 +95,26 @@ impl<F: Field> LinearForm<F> for UnivariateEvaluation<F> {
     /// See also [`Self::accumulate_many`] for a more efficient batched version.
     fn accumulate(&self, accumulator: &mut [F], scalar: F) {
         assert_eq!(accumulator.len(), self.size);
-        let mut power = scalar;
-        for entry in accumulator {
-            *entry += power;
-            power *= self.point;
+        match self.basis {
+            Basis::Coefficients => {
+                let mut power = scalar;
+                for entry in accumulator {
+                    *entry += power;
+                    power *= self.point;
+                }
+            }
+            Basis::Evaluations => eval_eq(accumulator, &self.expanded_point().0, scalar),
         }
     }
 }
 
 impl<M: Embedding> Evaluate<M> for UnivariateEvaluation<M::Target> {
     fn evaluate(&self, embedding: &M, vector: &[M::Source]) -> M::Target {
-        mixed_univariate_evaluate(embedding, vector, self.point)
+        match self.basis {
+            Basis::Coefficients => mixed_univariate_evaluate(embedding, vector, self.point),
+            Basis::Evaluations => {
+                mixed_multilinear_extend(embedding, vector, &self.expanded_point().0)
+            }
+        }
     }
 }
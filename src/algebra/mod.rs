@@ -1,14 +1,18 @@
+mod basis;
 pub mod embedding;
 pub mod fields;
 pub mod linear_form;
 mod multilinear;
 mod multilinear_point;
+mod multilinear_polynomial;
 pub mod ntt;
 pub mod sumcheck;
 
 use ark_ff::{AdditiveGroup, Field};
+pub use basis::Basis;
 pub use multilinear::{eval_eq, mixed_multilinear_extend, multilinear_extend};
 pub use multilinear_point::MultilinearPoint;
+pub use multilinear_polynomial::MultilinearPolynomial;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
@@ -79,6 +83,37 @@ pub fn univariate_evaluate<F: Field>(coefficients: &[F], point: F) -> F {
     mixed_univariate_evaluate(&embedding::Identity::new(), coefficients, point)
 }
 
+/// Coefficients of the polynomial of degree `< n` through `(i, evaluations[i])` for `i < n`.
+///
+/// Uses Newton's forward differences, so the characteristic must exceed `n`.
+pub fn univariate_interpolate<F: Field>(evaluations: &[F]) -> Vec<F> {
+    let n = evaluations.len();
+    let mut differences = evaluations.to_vec();
+    for k in 1..n {
+        for i in (k..n).rev() {
+            let previous = differences[i - 1];
+            differences[i] -= previous;
+        }
+    }
+
+    // p(X) = ∑_k Δ^k p(0) / k! · X (X - 1) ⋯ (X - k + 1)
+    let mut coefficients = vec![F::ZERO; n];
+    let mut basis = vec![F::ONE];
+    let mut factorial = F::ONE;
+    for (k, difference) in differences.into_iter().enumerate() {
+        let scale = difference * factorial.inverse().expect("Characteristic too small");
+        scalar_mul_add(&mut coefficients[..basis.len()], scale, &basis);
+        let k = F::from(k as u64);
+        basis.push(F::ZERO);
+        for i in (1..basis.len()).rev() {
+            basis[i] = basis[i - 1] - k * basis[i];
+        }
+        basis[0] *= -k;
+        factorial *= k + F::ONE;
+    }
+    coefficients
+}
+
 /// Mixed field univariate Horner evaluation.
 pub fn mixed_univariate_evaluate<M: Embedding>(
     embedding: &M,
@@ -17,6 +17,41 @@ where
         self.0.len()
     }
 
+    /// The boolean hypercube point corresponding to `index`.
+    ///
+    /// `index` is interpreted as a **big-endian** binary number, such that the
+    /// multilinear extension of a vector in this point equals `vector[index]`.
+    pub fn from_index(index: usize, num_variables: usize) -> Self {
+        assert!(index < (1 << num_variables));
+        Self(
+            (0..num_variables)
+                .rev()
+                .map(|i| {
+                    if (index >> i) & 1 == 1 {
+                        F::ONE
+                    } else {
+                        F::ZERO
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// The point `(x^(2^(n-1)), …, x^2, x)`.
+    ///
+    /// The multilinear extension of a vector in this point equals the univariate evaluation in
+    /// `x` of its monomial coefficients, see [`super::Basis::Evaluations`].
+    pub fn expand_from_univariate(point: F, num_variables: usize) -> Self {
+        let mut result = Vec::with_capacity(num_variables);
+        let mut power = point;
+        for _ in 0..num_variables {
+            result.push(power);
+            power.square_in_place();
+        }
+        result.reverse();
+        Self(result)
+    }
+
     /// Computes the equality polynomial `eq(c, p)`, where `p` is binary.
     ///
     /// The **equality polynomial** is defined as:
@@ -76,7 +111,9 @@ mod tests {
     use ark_std::rand::thread_rng;
 
     use super::*;
-    use crate::algebra::fields::Field64;
+    use crate::algebra::{
+        fields::Field64, multilinear_extend, ntt::wavelet_transform, univariate_evaluate,
+    };
 
     #[test]
     fn test_n_variables() {
@@ -218,6 +255,26 @@ mod tests {
         assert_eq!(point.eq_poly(0b11), Field64::from(0));
     }
 
+    #[test]
+    fn test_from_index() {
+        let point = MultilinearPoint::<Field64>::from_index(0b1101, 4);
+        assert_eq!(
+            point.0,
+            vec![Field64::ONE, Field64::ONE, Field64::ZERO, Field64::ONE]
+        );
+        for index in 0..16 {
+            let point = MultilinearPoint::<Field64>::from_index(index, 4);
+            for other in 0..16 {
+                let expected = if index == other {
+                    Field64::ONE
+                } else {
+                    Field64::ZERO
+                };
+                assert_eq!(point.eq_poly(other), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_multilinear_point_rand_not_all_same() {
         const K: usize = 20; // Number of trials
@@ -243,4 +300,19 @@ mod tests {
             "rand generated uniform points in all {K} trials"
         );
     }
+
+    #[test]
+    fn test_expand_from_univariate() {
+        let mut rng = thread_rng();
+        let coeffs = (0..16).map(|_| rng.gen()).collect::<Vec<Field64>>();
+        let mut evals = coeffs.clone();
+        wavelet_transform(&mut evals);
+        let x: Field64 = rng.gen();
+        let point = MultilinearPoint::expand_from_univariate(x, 4);
+        assert_eq!(point.0, vec![x.pow([8]), x.pow([4]), x.square(), x]);
+        assert_eq!(
+            multilinear_extend(&evals, &point.0),
+            univariate_evaluate(&coeffs, x)
+        );
+    }
 }
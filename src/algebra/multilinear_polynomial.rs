@@ -0,0 +1,371 @@
+use std::{
+    borrow::Cow,
+    ops::{Add, AddAssign, Mul, MulAssign},
+};
+
+use ark_ff::Field;
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{
+    embedding::{Embedding, Identity},
+    eval_eq, lift, mixed_dot, mixed_multilinear_extend,
+    ntt::{inverse_wavelet_transform, wavelet_transform},
+    sumcheck::{mixed_fold_weighted, ProductCovector},
+    tensor_product, Basis, MultilinearPoint,
+};
+
+/// A multilinear polynomial in `n` variables, stored as `2^n` values in a [`Basis`].
+///
+/// Variables are ordered most significant first, as in [`MultilinearPoint::from_index`]. In
+/// [`Basis::Evaluations`] the values are the evaluations on the boolean hypercube, in
+/// [`Basis::Coefficients`] the value at index `i` is the coefficient of the monomial
+/// `∏_k x_k^(i_k)` with `i_k` the bits of `i`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MultilinearPolynomial<F> {
+    values: Vec<F>,
+    basis: Basis,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    /// The polynomial with `values` in `basis`. The number of values must be a power of two.
+    pub fn new(values: Vec<F>, basis: Basis) -> Self {
+        assert!(values.len().is_power_of_two());
+        Self { values, basis }
+    }
+
+    /// The polynomial with the given evaluations on the boolean hypercube.
+    pub fn from_evaluations(evaluations: Vec<F>) -> Self {
+        Self::new(evaluations, Basis::Evaluations)
+    }
+
+    /// The polynomial with the given monomial coefficients.
+    pub fn from_coefficients(coefficients: Vec<F>) -> Self {
+        Self::new(coefficients, Basis::Coefficients)
+    }
+
+    pub const fn num_variables(&self) -> usize {
+        self.values.len().trailing_zeros() as usize
+    }
+
+    pub const fn basis(&self) -> Basis {
+        self.basis
+    }
+
+    /// The values in the current [`Self::basis`].
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<F> {
+        self.values
+    }
+
+    /// The values in `basis`, converting a copy if needed.
+    pub fn values_in(&self, basis: Basis) -> Cow<'_, [F]> {
+        if basis == self.basis {
+            Cow::Borrowed(&self.values)
+        } else {
+            Cow::Owned(self.clone().into_basis(basis).values)
+        }
+    }
+
+    /// Convert the values to `basis` in place, using the wavelet transform.
+    pub fn set_basis(&mut self, basis: Basis) {
+        match (self.basis, basis) {
+            (Basis::Coefficients, Basis::Evaluations) => wavelet_transform(&mut self.values),
+            (Basis::Evaluations, Basis::Coefficients) => {
+                inverse_wavelet_transform(&mut self.values);
+            }
+            _ => {}
+        }
+        self.basis = basis;
+    }
+
+    #[must_use]
+    pub fn into_basis(mut self, basis: Basis) -> Self {
+        self.set_basis(basis);
+        self
+    }
+
+    /// Evaluate the polynomial in `point`.
+    pub fn evaluate(&self, point: &MultilinearPoint<F>) -> F {
+        self.mixed_evaluate(&Identity::<F>::new(), point)
+    }
+
+    /// Evaluate the polynomial in a `point` over the target field of `embedding`.
+    pub fn mixed_evaluate<M>(&self, embedding: &M, point: &MultilinearPoint<M::Target>) -> M::Target
+    where
+        M: Embedding<Source = F>,
+    {
+        assert_eq!(point.num_variables(), self.num_variables());
+        match self.basis {
+            Basis::Evaluations => mixed_multilinear_extend(embedding, &self.values, &point.0),
+            Basis::Coefficients => ProductCovector {
+                scalar: M::Target::ONE,
+                factors: point.0.iter().map(|&x| (M::Target::ONE, x)).collect(),
+            }
+            .mixed_dot(embedding, &self.values),
+        }
+    }
+
+    /// Fix the first (most significant) `prefix.len()` variables to `prefix`.
+    #[must_use]
+    pub fn fix_prefix(&self, prefix: &[F]) -> Self {
+        self.mixed_fix_prefix(&Identity::<F>::new(), prefix)
+    }
+
+    /// Mixed field [`Self::fix_prefix`] returning a polynomial over the target field.
+    pub fn mixed_fix_prefix<M>(
+        &self,
+        embedding: &M,
+        prefix: &[M::Target],
+    ) -> MultilinearPolynomial<M::Target>
+    where
+        M: Embedding<Source = F>,
+    {
+        assert!(prefix.len() <= self.num_variables());
+        let weights = fixing_weights(self.basis, prefix);
+        MultilinearPolynomial {
+            values: mixed_fold_weighted(embedding, &self.values, &weights),
+            basis: self.basis,
+        }
+    }
+
+    /// Fix the last (least significant) `suffix.len()` variables to `suffix`.
+    #[must_use]
+    pub fn fix_suffix(&self, suffix: &[F]) -> Self {
+        self.mixed_fix_suffix(&Identity::<F>::new(), suffix)
+    }
+
+    /// Mixed field [`Self::fix_suffix`] returning a polynomial over the target field.
+    pub fn mixed_fix_suffix<M>(
+        &self,
+        embedding: &M,
+        suffix: &[M::Target],
+    ) -> MultilinearPolynomial<M::Target>
+    where
+        M: Embedding<Source = F>,
+    {
+        assert!(suffix.len() <= self.num_variables());
+        let weights = fixing_weights(self.basis, suffix);
+
+        #[cfg(not(feature = "parallel"))]
+        let values = self
+            .values
+            .chunks_exact(weights.len())
+            .map(|chunk| mixed_dot(embedding, &weights, chunk))
+            .collect();
+
+        #[cfg(feature = "parallel")]
+        let values = self
+            .values
+            .par_chunks_exact(weights.len())
+            .map(|chunk| mixed_dot(embedding, &weights, chunk))
+            .collect();
+
+        MultilinearPolynomial {
+            values,
+            basis: self.basis,
+        }
+    }
+
+    /// Multiply the evaluations on the hypercube by `eq(point, ·)`.
+    ///
+    /// The result agrees with the product on the hypercube, so its sum over the hypercube is
+    /// the evaluation of `self` in `point`.
+    pub fn mul_eq(&mut self, point: &MultilinearPoint<F>) {
+        assert_eq!(point.num_variables(), self.num_variables());
+        self.set_basis(Basis::Evaluations);
+        let mut weights = vec![F::ZERO; self.values.len()];
+        eval_eq(&mut weights, &point.0, F::ONE);
+        for (value, weight) in self.values.iter_mut().zip(weights) {
+            *value *= weight;
+        }
+    }
+
+    /// Lift the polynomial to the target field of `embedding`.
+    pub fn lift<M: Embedding<Source = F>>(
+        &self,
+        embedding: &M,
+    ) -> MultilinearPolynomial<M::Target> {
+        MultilinearPolynomial {
+            values: lift(embedding, &self.values),
+            basis: self.basis,
+        }
+    }
+}
+
+impl<F: Field> MultilinearPolynomial<F>
+where
+    Standard: Distribution<F>,
+{
+    pub fn rand(rng: &mut impl RngCore, num_variables: usize, basis: Basis) -> Self {
+        Self::new((0..1 << num_variables).map(|_| rng.gen()).collect(), basis)
+    }
+}
+
+/// The weights of the blocks when fixing the most significant variables to `point`.
+fn fixing_weights<F: Field>(basis: Basis, point: &[F]) -> Vec<F> {
+    point.iter().fold(vec![F::ONE], |acc, &x| match basis {
+        Basis::Evaluations => tensor_product(&acc, &[F::ONE - x, x]),
+        Basis::Coefficients => tensor_product(&acc, &[F::ONE, x]),
+    })
+}
+
+impl<F: Field> AddAssign<&Self> for MultilinearPolynomial<F> {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.values.len(), rhs.values.len());
+        for (value, rhs) in self.values.iter_mut().zip(rhs.values_in(self.basis).iter()) {
+            *value += rhs;
+        }
+    }
+}
+
+impl<F: Field> Add<&Self> for MultilinearPolynomial<F> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<F: Field> MulAssign<F> for MultilinearPolynomial<F> {
+    fn mul_assign(&mut self, rhs: F) {
+        for value in &mut self.values {
+            *value *= rhs;
+        }
+    }
+}
+
+impl<F: Field> Mul<F> for MultilinearPolynomial<F> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: F) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::AdditiveGroup;
+    use ark_std::rand::thread_rng;
+
+    use super::*;
+    use crate::algebra::{
+        embedding::Basefield,
+        fields::{Field64, Field64_2},
+        multilinear_extend,
+    };
+
+    const BASES: [Basis; 2] = [Basis::Coefficients, Basis::Evaluations];
+
+    #[test]
+    fn test_basis_conversion() {
+        let mut rng = thread_rng();
+        let poly = MultilinearPolynomial::<Field64>::rand(&mut rng, 5, Basis::Coefficients);
+        let evals = poly.clone().into_basis(Basis::Evaluations);
+        for index in 0..32 {
+            let point = MultilinearPoint::from_index(index, 5);
+            assert_eq!(poly.evaluate(&point), evals.values()[index]);
+        }
+        assert_eq!(evals.values_in(Basis::Coefficients), poly.values());
+        assert_eq!(evals.into_basis(Basis::Coefficients), poly);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut rng = thread_rng();
+        for basis in BASES {
+            let poly = MultilinearPolynomial::<Field64>::rand(&mut rng, 6, basis);
+            let point = MultilinearPoint::rand(&mut rng, 6);
+            let evals = poly.values_in(Basis::Evaluations);
+            assert_eq!(poly.evaluate(&point), multilinear_extend(&evals, &point.0));
+
+            // Evaluating in the expanded point equals the univariate evaluation of the
+            // coefficients.
+            let x: Field64 = rng.gen();
+            let coeffs = poly.values_in(Basis::Coefficients);
+            let expanded = MultilinearPoint::expand_from_univariate(x, 6);
+            let univariate = coeffs
+                .iter()
+                .rev()
+                .fold(Field64::ZERO, |acc, c| acc * x + c);
+            assert_eq!(poly.evaluate(&expanded), univariate);
+        }
+    }
+
+    #[test]
+    fn test_fix_variables() {
+        let mut rng = thread_rng();
+        for basis in BASES {
+            let poly = MultilinearPolynomial::<Field64>::rand(&mut rng, 6, basis);
+            let point = MultilinearPoint::<Field64>::rand(&mut rng, 6);
+            let expected = poly.evaluate(&point);
+            for split in 0..=6 {
+                let (prefix, suffix) = point.0.split_at(split);
+                let fixed = poly.fix_prefix(prefix);
+                assert_eq!(fixed.num_variables(), 6 - split);
+                assert_eq!(fixed.basis(), basis);
+                assert_eq!(fixed.evaluate(&MultilinearPoint(suffix.to_vec())), expected);
+
+                let fixed = poly.fix_suffix(suffix);
+                assert_eq!(fixed.num_variables(), split);
+                assert_eq!(fixed.evaluate(&MultilinearPoint(prefix.to_vec())), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mixed() {
+        let mut rng = thread_rng();
+        let embedding = Basefield::<Field64_2>::new();
+        for basis in BASES {
+            let poly = MultilinearPolynomial::<Field64>::rand(&mut rng, 5, basis);
+            let lifted = poly.lift(&embedding);
+            let point = MultilinearPoint::<Field64_2>::rand(&mut rng, 5);
+            let expected = lifted.evaluate(&point);
+            assert_eq!(poly.mixed_evaluate(&embedding, &point), expected);
+
+            let (prefix, suffix) = point.0.split_at(2);
+            let fixed = poly.mixed_fix_prefix(&embedding, prefix);
+            assert_eq!(fixed, lifted.fix_prefix(prefix));
+            assert_eq!(fixed.evaluate(&MultilinearPoint(suffix.to_vec())), expected);
+            let fixed = poly.mixed_fix_suffix(&embedding, suffix);
+            assert_eq!(fixed, lifted.fix_suffix(suffix));
+            assert_eq!(fixed.evaluate(&MultilinearPoint(prefix.to_vec())), expected);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = thread_rng();
+        let a = MultilinearPolynomial::<Field64>::rand(&mut rng, 4, Basis::Coefficients);
+        let b = MultilinearPolynomial::<Field64>::rand(&mut rng, 4, Basis::Evaluations);
+        let scalar: Field64 = rng.gen();
+        let point = MultilinearPoint::rand(&mut rng, 4);
+
+        let sum = a.clone() + &b;
+        assert_eq!(sum.basis(), Basis::Coefficients);
+        assert_eq!(
+            sum.evaluate(&point),
+            a.evaluate(&point) + b.evaluate(&point)
+        );
+        assert_eq!(
+            (b.clone() * scalar).evaluate(&point),
+            b.evaluate(&point) * scalar
+        );
+
+        let mut product = a.clone();
+        product.mul_eq(&point);
+        assert_eq!(product.basis(), Basis::Evaluations);
+        assert_eq!(product.values().iter().sum::<Field64>(), a.evaluate(&point));
+        let evals = a.values_in(Basis::Evaluations);
+        for index in 0..16 {
+            assert_eq!(product.values()[index], evals[index] * point.eq_poly(index));
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Reed-Solomon encoding for binary tower fields using the additive FFT of Gao and Mateer.
+//!
+//! The multiplicative groups of binary fields have odd order, so instead of cosets of a
+//! subgroup the codewords are evaluated on affine 𝔽_2-subspaces `shift + ⟨β_0, …, β_{m-1}⟩`,
+//! where `β_j` is the element with only bit `j` set. The symbol at index `i` is the evaluation
+//! at `shift + Σ_j i_j β_j`, i.e. at the element whose bits are those of `i`, shifted.
+
+use std::sync::Arc;
+
+use ark_ff::Field;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{inverse_wavelet_transform, scale_by_powers, transpose, NttContext, ReedSolomon};
+use crate::algebra::{fields::BinaryTowerField, Basis};
+
+/// Additive NTT backend for [`BinaryTowerField`]s.
+///
+/// The default encoder evaluates on the linear subspaces, i.e. without shift.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AdditiveNtt<F> {
+    shift: F,
+}
+
+impl<F: BinaryTowerField> AdditiveNtt<F> {
+    /// Encoder evaluating on the subspaces translated by `shift`.
+    pub const fn new(shift: F) -> Self {
+        Self { shift }
+    }
+
+    /// Evaluate a polynomial given by `len` coefficients, zero-padded to the power-of-two length
+    /// of `values`, on the subspace of that size.
+    fn transform(&self, twiddles: &[F], values: &mut [F], len: usize) {
+        let len = len.next_power_of_two().min(values.len());
+        if self.shift != F::ZERO {
+            taylor_shift(&mut values[..len], self.shift);
+        }
+        additive_fft(twiddles, values, len, &mut Vec::with_capacity(len));
+    }
+}
+
+impl<F: BinaryTowerField> ReedSolomon<F> for AdditiveNtt<F> {
+    fn interleaved_encode(
+        &self,
+        interleaved_coeffs: &[&[F]],
+        expansion: usize,
+        interleaving_depth: usize,
+        basis: Basis,
+    ) -> Vec<F> {
+        let size = interleaved_coeffs.iter().map(|c| c.len()).sum::<usize>() * expansion;
+        let mut result = vec![F::ZERO; size];
+        self.interleaved_encode_into(
+            NttContext::global(),
+            interleaved_coeffs,
+            expansion,
+            interleaving_depth,
+            basis,
+            F::ONE,
+            &mut result,
+        );
+        result
+    }
+
+    fn interleaved_encode_into(
+        &self,
+        context: &NttContext,
+        coeffs: &[&[F]],
+        expansion: usize,
+        interleaving_depth: usize,
+        basis: Basis,
+        coset_offset: F,
+        output: &mut [F],
+    ) {
+        assert!(expansion > 0);
+        if coeffs.is_empty() {
+            assert!(output.is_empty());
+            return;
+        }
+
+        let poly_size = coeffs[0].len();
+        assert!(poly_size.is_multiple_of(interleaving_depth));
+        for poly in coeffs {
+            assert_eq!(poly.len(), poly_size);
+        }
+
+        let block_size = poly_size / interleaving_depth;
+        let expanded_block = block_size * expansion;
+        assert!(expanded_block.is_power_of_two());
+        let per_poly_size = expanded_block * interleaving_depth;
+        assert_eq!(output.len(), per_poly_size * coeffs.len());
+
+        // Lay out coefficients in contiguous blocks and zero-pad each block.
+        let blocks = coeffs.iter().flat_map(|poly| poly.chunks_exact(block_size));
+        for (dst, block) in output.chunks_exact_mut(expanded_block).zip(blocks) {
+            let (head, padding) = dst.split_at_mut(block_size);
+            head.copy_from_slice(block);
+            if basis == Basis::Evaluations {
+                inverse_wavelet_transform(head);
+            }
+            scale_by_powers(head, coset_offset);
+            padding.fill(F::ZERO);
+        }
+
+        let twiddles = twiddles_in::<F>(context, expanded_block);
+        let transform = |block: &mut [F]| self.transform(&twiddles, block, block_size);
+        #[cfg(not(feature = "parallel"))]
+        output.chunks_exact_mut(expanded_block).for_each(transform);
+        #[cfg(feature = "parallel")]
+        output
+            .par_chunks_exact_mut(expanded_block)
+            .for_each(transform);
+
+        transpose(output, coeffs.len() * interleaving_depth, expanded_block);
+    }
+
+    fn precompute(&self, context: &NttContext, size: usize) {
+        if size.is_power_of_two() {
+            twiddles_in::<F>(context, size);
+        }
+    }
+
+    /// Subspaces of power-of-two order up to the size of the field.
+    fn supports(&self, size: usize) -> bool {
+        size.is_power_of_two() && u64::from(size.trailing_zeros()) <= F::extension_degree()
+    }
+
+    fn evaluation_point(&self, size: usize, index: usize) -> F {
+        assert!(index < size);
+        self.shift + subspace_element::<F>(index)
+    }
+}
+
+/// The element of the subspace whose bits are those of `index`.
+fn subspace_element<F: BinaryTowerField>(index: usize) -> F {
+    F::from_u128(index as u128).expect("Subspace larger than field")
+}
+
+/// The [`twiddles`] for `size`, retained in `context`.
+fn twiddles_in<F: BinaryTowerField>(context: &NttContext, size: usize) -> Arc<Vec<F>> {
+    context.table::<AdditiveNtt<F>, F>(size, || twiddles(size))
+}
+
+/// Twiddles of every recursion level of [`additive_fft`] on the subspace of order `size`.
+///
+/// A level on the span of `β_0, …, β_{k-1}` stores `β_{k-1}` followed by the span of
+/// `γ_j = β_j / β_{k-1}` for `j < k - 1`, and the next level is the span of `γ_j^2 + γ_j`.
+fn twiddles<F: BinaryTowerField>(size: usize) -> Vec<F> {
+    assert!(size.is_power_of_two());
+    let mut basis = (0..size.trailing_zeros())
+        .map(|j| subspace_element::<F>(1 << j))
+        .collect::<Vec<F>>();
+    let mut result = Vec::with_capacity(size + basis.len());
+    while let Some(scale) = basis.pop() {
+        let inverse = scale.inverse().expect("Basis must be linearly independent");
+        result.push(scale);
+        let start = result.len();
+        result.push(F::ZERO);
+        for gamma in &mut basis {
+            *gamma *= inverse;
+            let end = result.len();
+            for i in start..end {
+                result.push(result[i] + *gamma);
+            }
+            *gamma += gamma.square();
+        }
+    }
+    result
+}
+
+/// Evaluate the polynomial with the `len` leading coefficients of `values` on the subspace of
+/// [`twiddles`], in place.
+///
+/// Writing `g(X) = f(β X) = g_0(X^2 + X) + X g_1(X^2 + X)` for the last basis element `β`, the
+/// evaluations at `β γ` and `β (γ + 1)` for `γ` in the span of the other `γ_j` follow from those
+/// of `g_0` and `g_1` at `γ^2 + γ`, which is a subspace of half the size.
+fn additive_fft<F: Field>(twiddles: &[F], values: &mut [F], len: usize, scratch: &mut Vec<F>) {
+    let size = values.len();
+    if size == 1 {
+        return;
+    }
+    if len <= 1 {
+        let constant = values[0];
+        values.fill(constant);
+        return;
+    }
+    let half = size / 2;
+    let (scale, twiddles) = twiddles.split_first().unwrap();
+    let (span, twiddles) = twiddles.split_at(half);
+
+    let mut power = F::ONE;
+    for value in &mut values[..len] {
+        *value *= power;
+        power *= scale;
+    }
+    taylor_expansion(&mut values[..len]);
+
+    // Even coefficients are g_0, odd ones g_1.
+    scratch.clear();
+    scratch.extend_from_slice(&values[..len]);
+    values.fill(F::ZERO);
+    let (low, high) = values.split_at_mut(half);
+    for ((low, high), pair) in low
+        .iter_mut()
+        .zip(high.iter_mut())
+        .zip(scratch.chunks_exact(2))
+    {
+        *low = pair[0];
+        *high = pair[1];
+    }
+    additive_fft(twiddles, low, len / 2, scratch);
+    additive_fft(twiddles, high, len / 2, scratch);
+
+    for ((low, high), &gamma) in low.iter_mut().zip(high.iter_mut()).zip(span) {
+        *low += gamma * *high;
+        *high += *low;
+    }
+}
+
+/// Rewrite a polynomial with a power-of-two number of coefficients in the basis
+/// `X^i (X^2 + X)^j` for `i < 2`, in place.
+///
+/// Uses `(X^2 + X)^k = X^{2k} + X^k` for powers of two `k` in characteristic two.
+fn taylor_expansion<F: Field>(values: &mut [F]) {
+    let size = values.len();
+    if size <= 2 {
+        return;
+    }
+    // Divide f = f_0 + X^k f_1 + X^2k f_2 + X^3k f_3 by X^2k + X^k.
+    let quarter = size / 4;
+    let (remainder, quotient) = values.split_at_mut(2 * quarter);
+    let (quotient_low, quotient_high) = quotient.split_at_mut(quarter);
+    for ((r, q), &high) in remainder[quarter..]
+        .iter_mut()
+        .zip(quotient_low.iter_mut())
+        .zip(quotient_high.iter())
+    {
+        *q += high;
+        *r += *q;
+    }
+    taylor_expansion(remainder);
+    taylor_expansion(quotient);
+}
+
+/// Replace the coefficients of `f(X)` by those of `f(X + shift)`, in place.
+///
+/// Uses `(X + shift)^k = X^k + shift^k` for powers of two `k` in characteristic two.
+fn taylor_shift<F: Field>(values: &mut [F], shift: F) {
+    let size = values.len();
+    if size <= 1 {
+        return;
+    }
+    let half = size / 2;
+    let (low, high) = values.split_at_mut(half);
+    taylor_shift(low, shift);
+    taylor_shift(high, shift);
+    let power = shift.pow([half as u64]);
+    for (low, high) in low.iter_mut().zip(high.iter()) {
+        *low += power * *high;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use super::*;
+    use crate::algebra::{
+        fields::{BinaryField128, BinaryField16, BinaryField8},
+        univariate_evaluate,
+    };
+
+    fn check_against_evaluation<F: BinaryTowerField>(shift: F) {
+        let mut rng = ark_std::test_rng();
+        let encoder = AdditiveNtt::new(shift);
+        for (poly_size, num_polys, expansion, interleaving_depth) in [
+            (1, 1, 1, 1),
+            (1, 2, 4, 1),
+            (8, 1, 2, 1),
+            (12, 1, 4, 3),
+            (16, 3, 4, 4),
+            (64, 2, 2, 8),
+        ] {
+            let polys = (0..num_polys)
+                .map(|_| {
+                    (0..poly_size)
+                        .map(|_| F::rand(&mut rng))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let polys = polys.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            let block_size = poly_size / interleaving_depth;
+            let size = block_size * expansion;
+            let encoded = encoder.interleaved_encode(
+                &polys,
+                expansion,
+                interleaving_depth,
+                Basis::Coefficients,
+            );
+            let blocks = polys.iter().flat_map(|poly| poly.chunks_exact(block_size));
+            let num_blocks = num_polys * interleaving_depth;
+            for (column, block) in blocks.enumerate() {
+                for index in 0..size {
+                    let point = encoder.evaluation_point(size, index);
+                    assert_eq!(
+                        encoded[index * num_blocks + column],
+                        univariate_evaluate(block, point)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_subspace() {
+        check_against_evaluation(BinaryField8::default());
+        check_against_evaluation(BinaryField16::default());
+        check_against_evaluation(BinaryField128::default());
+    }
+
+    #[test]
+    fn test_affine_subspace() {
+        check_against_evaluation(BinaryField8::new(0xa7));
+        check_against_evaluation(BinaryField128::new(1 << 100 | 3));
+    }
+
+    #[test]
+    fn test_full_field() {
+        // The subspace of order 2^8 is all of 𝔽_{2^8}.
+        let mut rng = ark_std::test_rng();
+        let coeffs = (0..64)
+            .map(|_| BinaryField8::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let encoder = AdditiveNtt::<BinaryField8>::default();
+        let encoded = encoder.interleaved_encode(&[&coeffs], 4, 1, Basis::Coefficients);
+        for (index, value) in encoded.iter().enumerate() {
+            let point = BinaryField8::new(index as u8);
+            assert_eq!(*value, univariate_evaluate(&coeffs, point));
+        }
+    }
+
+    #[test]
+    fn test_evaluation_basis() {
+        let mut rng = ark_std::test_rng();
+        let evals = (0..16)
+            .map(|_| BinaryField16::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let mut coeffs = evals.clone();
+        coeffs
+            .chunks_exact_mut(8)
+            .for_each(inverse_wavelet_transform);
+        let encoder = AdditiveNtt::new(BinaryField16::new(0x1234));
+        assert_eq!(
+            encoder.interleaved_encode(&[&evals], 2, 2, Basis::Evaluations),
+            encoder.interleaved_encode(&[&coeffs], 2, 2, Basis::Coefficients)
+        );
+    }
+}
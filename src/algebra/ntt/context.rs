@@ -0,0 +1,282 @@
+//! Twiddle tables with bounded memory.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use ark_ff::FftField;
+
+use super::cooley_tukey::{powers, reverse_tails, NttEngine};
+
+static GLOBAL: LazyLock<NttContext> = LazyLock::new(NttContext::default);
+
+/// Owns the precomputed tables used by the Reed-Solomon encoders.
+///
+/// Tables are computed on first use and kept until their total size exceeds the memory budget,
+/// after which the least recently used tables are evicted. A table larger than the whole budget
+/// is computed but not retained.
+///
+/// A context can be warmed ahead of time for a protocol configuration, e.g. with
+/// [`crate::protocols::whir::Config::precompute_ntt`], and passed to the prover.
+pub struct NttContext {
+    budget: usize,
+    cache: Mutex<Cache>,
+}
+
+#[derive(Default)]
+struct Cache {
+    tables: HashMap<(TypeId, usize), Entry>,
+    bytes: usize,
+    clock: u64,
+}
+
+struct Entry {
+    table: Arc<dyn Any + Send + Sync>,
+    bytes: usize,
+    last_used: u64,
+}
+
+impl Default for NttContext {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_BUDGET)
+    }
+}
+
+impl fmt::Debug for NttContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cache = self.cache.lock().expect("Lock poisoned");
+        f.debug_struct("NttContext")
+            .field("budget", &self.budget)
+            .field("tables", &cache.tables.len())
+            .field("bytes", &cache.bytes)
+            .finish()
+    }
+}
+
+impl NttContext {
+    /// Memory budget in bytes of default contexts, including [`Self::global`].
+    ///
+    /// This retains the roots for transforms of up to `2^25` elements of a 64-bit field, or
+    /// `2^23` elements of a 256-bit field. Tables of larger codewords are recomputed on every
+    /// call, which costs one multiplication per element on top of the transform itself. Provers
+    /// committing to larger codewords should pass a context created with a larger budget, e.g.
+    /// `NttContext::new(usize::MAX)` to retain every table.
+    pub const DEFAULT_BUDGET: usize = 256 << 20;
+
+    /// Create an empty context retaining at most `budget` bytes of tables.
+    pub fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            cache: Mutex::new(Cache::default()),
+        }
+    }
+
+    /// The process-wide context used when none is passed explicitly.
+    pub fn global() -> &'static Self {
+        &GLOBAL
+    }
+
+    /// The memory budget in bytes.
+    pub const fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// The number of bytes currently retained.
+    pub fn memory_usage(&self) -> usize {
+        self.cache.lock().expect("Lock poisoned").bytes
+    }
+
+    /// Drop all retained tables.
+    pub fn clear(&self) {
+        let mut cache = self.cache.lock().expect("Lock poisoned");
+        cache.tables.clear();
+        cache.bytes = 0;
+    }
+
+    /// Returns the table of `T`s for transforms of size `size`, computing it with `init` if it
+    /// is not retained.
+    ///
+    /// The key type `K` namespaces the tables of different encoders, e.g. by field.
+    pub fn table<K, T>(&self, size: usize, init: impl FnOnce() -> Vec<T>) -> Arc<Vec<T>>
+    where
+        K: 'static,
+        T: Send + Sync + 'static,
+    {
+        let key = (TypeId::of::<(K, T)>(), size);
+        {
+            let mut cache = self.cache.lock().expect("Lock poisoned");
+            cache.clock += 1;
+            let clock = cache.clock;
+            if let Some(entry) = cache.tables.get_mut(&key) {
+                entry.last_used = clock;
+                return entry.table.clone().downcast().unwrap();
+            }
+        }
+
+        // Compute without holding the lock. Concurrent misses may compute the table twice.
+        let table = Arc::new(init());
+        let bytes = table.len() * size_of::<T>();
+        if bytes > self.budget {
+            return table;
+        }
+
+        let mut cache = self.cache.lock().expect("Lock poisoned");
+        if let Some(entry) = cache.tables.get(&key) {
+            return entry.table.clone().downcast().unwrap();
+        }
+        while cache.bytes + bytes > self.budget {
+            let oldest = cache
+                .tables
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key)
+                .expect("Retained tables exceed the budget");
+            let evicted = cache.tables.remove(&oldest).unwrap();
+            cache.bytes -= evicted.bytes;
+        }
+        cache.clock += 1;
+        let last_used = cache.clock;
+        cache.bytes += bytes;
+        cache.tables.insert(
+            key,
+            Entry {
+                table: table.clone(),
+                bytes,
+                last_used,
+            },
+        );
+        table
+    }
+
+    /// The powers `ω^i` for `i < size` of a primitive `size`-th root of unity.
+    pub fn roots<F: FftField>(&self, size: usize) -> Arc<Vec<F>> {
+        self.table::<F, F>(size, || {
+            powers(NttEngine::<F>::new_from_cache().root(size), size)
+        })
+    }
+
+    /// Compute the NTT of a slice of field elements.
+    pub fn ntt<F: FftField>(&self, values: &mut [F]) {
+        self.ntt_batch(values, values.len());
+    }
+
+    /// Compute many NTTs of size `size`.
+    pub fn ntt_batch<F: FftField>(&self, values: &mut [F], size: usize) {
+        if size == 0 {
+            return;
+        }
+        let roots = self.roots::<F>(size);
+        NttEngine::<F>::new_from_cache().ntt_batch_with_roots(values, &roots, size);
+    }
+
+    /// Compute the inverse NTT of a slice of field elements, without the 1/n scaling factor.
+    pub fn intt<F: FftField>(&self, values: &mut [F]) {
+        self.intt_batch(values, values.len());
+    }
+
+    /// Compute many inverse NTTs of size `size`, without the 1/n scaling factor.
+    pub fn intt_batch<F: FftField>(&self, values: &mut [F], size: usize) {
+        if size == 0 {
+            return;
+        }
+        reverse_tails(values, size);
+        self.ntt_batch(values, size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{AdditiveGroup, Field};
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::fields::Field64;
+
+    #[test]
+    fn test_ntt_matches_engine() {
+        let mut rng = ark_std::test_rng();
+        let context = NttContext::default();
+        for size in [1, 2, 8, 64, 256] {
+            let values: Vec<_> = (0..size * 3).map(|_| Field64::rand(&mut rng)).collect();
+            let mut expected = values.clone();
+            NttEngine::<Field64>::new_from_fftfield().ntt_batch(&mut expected, size);
+            let mut actual = values.clone();
+            context.ntt_batch(&mut actual, size);
+            assert_eq!(actual, expected);
+
+            context.intt_batch(&mut actual, size);
+            let scale = Field64::from(size as u64);
+            for (actual, value) in actual.iter().zip(&values) {
+                assert_eq!(*actual, *value * scale);
+            }
+        }
+        let roots = context.roots::<Field64>(16);
+        assert_eq!(roots[1], NttEngine::<Field64>::new_from_cache().root(16));
+        assert_eq!(roots[1].pow([16]), Field64::ONE);
+    }
+
+    #[test]
+    fn test_budget_eviction() {
+        let table = |size| move || vec![0_u64; size];
+        let context = NttContext::new(100 * size_of::<u64>());
+        let _ = context.table::<(), u64>(50, table(50));
+        let _ = context.table::<(), u64>(30, table(30));
+        let _ = context.table::<(), u64>(50, || unreachable!());
+        assert_eq!(context.memory_usage(), 80 * size_of::<u64>());
+
+        // Evicts the least recently used table.
+        let _ = context.table::<(), u64>(40, table(40));
+        assert_eq!(context.memory_usage(), 90 * size_of::<u64>());
+        let _ = context.table::<(), u64>(30, table(30));
+        assert_eq!(context.memory_usage(), 70 * size_of::<u64>());
+        let _ = context.table::<(), u64>(40, || unreachable!());
+
+        // Tables exceeding the budget are not retained.
+        let large = context.table::<(), u64>(200, table(200));
+        assert_eq!(large.len(), 200);
+        assert_eq!(context.memory_usage(), 70 * size_of::<u64>());
+
+        // Tables are namespaced by key and element type.
+        let _ = context.table::<Field64, u64>(10, table(10));
+        let _ = context.table::<(), u32>(10, || vec![0; 10]);
+        assert_eq!(
+            context.memory_usage(),
+            80 * size_of::<u64>() + 10 * size_of::<u32>()
+        );
+
+        context.clear();
+        assert_eq!(context.memory_usage(), 0);
+    }
+
+    #[test]
+    fn test_over_budget_recomputed() {
+        assert_eq!(NttContext::default().budget(), NttContext::DEFAULT_BUDGET);
+        assert_eq!(
+            NttContext::DEFAULT_BUDGET / size_of::<Field64>(),
+            1 << 25,
+            "Default budget retains the roots of 2^25 Goldilocks elements"
+        );
+
+        // Tables exceeding the budget are computed on every call.
+        let context = NttContext::new(16 * size_of::<Field64>());
+        let mut calls = 0;
+        for _ in 0..3 {
+            let _ = context.table::<(), Field64>(32, || {
+                calls += 1;
+                vec![Field64::ZERO; 32]
+            });
+        }
+        assert_eq!(calls, 3);
+        assert_eq!(context.memory_usage(), 0);
+
+        // Transforms are still correct without retained tables.
+        let expected = NttContext::default().roots::<Field64>(32);
+        assert_eq!(context.roots::<Field64>(32), expected);
+        assert_eq!(context.memory_usage(), 0);
+        let _ = context.roots::<Field64>(16);
+        assert_eq!(context.memory_usage(), 16 * size_of::<Field64>());
+    }
+}
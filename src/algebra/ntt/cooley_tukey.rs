@@ -1,7 +1,7 @@
 //! Number-theoretic transforms (NTTs) over fields with high two-adicity.
 //!
 //! Implements the √N Cooley-Tukey six-step algorithm to achieve parallelism with good locality.
-//! A global cache is used for twiddle factors.
+//! The free functions take their twiddle factors from [`NttContext::global`].
 
 use std::{
     any::{Any, TypeId},
@@ -16,6 +16,7 @@ use {crate::utils::workload_size, rayon::prelude::*, std::cmp::max};
 use super::{
     transpose,
     utils::{lcm, sqrt_factor},
+    NttContext,
 };
 
 /// Global cache for NTT engines, indexed by field.
@@ -45,28 +46,79 @@ pub struct NttEngine<F: Field> {
 }
 
 /// Returns the root-of-unity used for a domain of size `size`.
+///
+/// This is the same root as `NttEngine::checked_root`, derived from the two-adic root of
+/// unity without building an engine, so it is `None` rather than a panic for fields without
+/// even-order subgroups.
 pub fn generator<F: FftField>(size: usize) -> Option<F> {
-    NttEngine::<F>::new_from_cache().checked_root(size)
+    let log_size = size.trailing_zeros();
+    if !size.is_power_of_two() || log_size > F::TWO_ADICITY.min(63) {
+        return None;
+    }
+    let mut root = F::TWO_ADIC_ROOT_OF_UNITY;
+    for _ in log_size..F::TWO_ADICITY {
+        root.square_in_place();
+    }
+    Some(root)
 }
 
-/// Compute the NTT of a slice of field elements using a cached engine.
+/// Compute the NTT of a slice of field elements using the global context.
 pub fn ntt<F: FftField>(values: &mut [F]) {
-    NttEngine::<F>::new_from_cache().ntt(values);
+    NttContext::global().ntt(values);
 }
 
-/// Compute the many NTTs of size `size` using a cached engine.
+/// Compute the many NTTs of size `size` using the global context.
 pub fn ntt_batch<F: FftField>(values: &mut [F], size: usize) {
-    NttEngine::<F>::new_from_cache().ntt_batch(values, size);
+    NttContext::global().ntt_batch(values, size);
 }
 
-/// Compute the inverse NTT of a slice of field element without the 1/n scaling factor, using a cached engine.
+/// Compute the inverse NTT of a slice of field element without the 1/n scaling factor, using the global context.
 pub fn intt<F: FftField>(values: &mut [F]) {
-    NttEngine::<F>::new_from_cache().intt(values);
+    NttContext::global().intt(values);
 }
 
-/// Compute the inverse NTT of multiple slice of field elements, each of size `size`, without the 1/n scaling factor and using a cached engine.
+/// Compute the inverse NTT of multiple slice of field elements, each of size `size`, without the 1/n scaling factor and using the global context.
 pub fn intt_batch<F: FftField>(values: &mut [F], size: usize) {
-    NttEngine::<F>::new_from_cache().intt_batch(values, size);
+    NttContext::global().intt_batch(values, size);
+}
+
+/// The powers `root^i` for `i < size`.
+pub(super) fn powers<F: Field>(root: F, size: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(size);
+    #[cfg(not(feature = "parallel"))]
+    {
+        let mut root_i = F::ONE;
+        for _ in 0..size {
+            result.push(root_i);
+            root_i *= root;
+        }
+    }
+    #[cfg(feature = "parallel")]
+    result.par_extend((0..size).into_par_iter().map_with(F::ZERO, |root_i, i| {
+        if root_i.is_zero() {
+            *root_i = root.pow([i as u64]);
+        } else {
+            *root_i *= root;
+        }
+        *root_i
+    }));
+    result
+}
+
+/// Reverses all but the first element of each chunk of size `size`, turning an NTT into an
+/// inverse NTT without the 1/n scaling factor.
+pub(super) fn reverse_tails<F: Send>(values: &mut [F], size: usize) {
+    assert!(values.len().is_multiple_of(size));
+
+    #[cfg(not(feature = "parallel"))]
+    values.chunks_exact_mut(size).for_each(|values| {
+        values[1..].reverse();
+    });
+
+    #[cfg(feature = "parallel")]
+    values.par_chunks_exact_mut(size).for_each(|values| {
+        values[1..].reverse();
+    });
 }
 
 impl<F: FftField> NttEngine<F> {
@@ -146,9 +198,15 @@ impl<F: Field> NttEngine<F> {
     }
 
     pub fn ntt_batch(&self, values: &mut [F], size: usize) {
-        assert!(values.len().is_multiple_of(size));
         let roots = self.roots_table(size);
-        self.ntt_dispatch(values, &roots, size);
+        self.ntt_batch_with_roots(values, &roots, size);
+    }
+
+    /// Batch NTT using a table of `roots` whose length is a multiple of `size`.
+    pub(super) fn ntt_batch_with_roots(&self, values: &mut [F], roots: &[F], size: usize) {
+        assert!(values.len().is_multiple_of(size));
+        assert!(roots.len().is_multiple_of(size));
+        self.ntt_dispatch(values, roots, size);
     }
 
     /// Inverse NTT. Does not aply 1/n scaling factor.
@@ -159,18 +217,7 @@ impl<F: Field> NttEngine<F> {
 
     /// Inverse batch NTT. Does not aply 1/n scaling factor.
     pub fn intt_batch(&self, values: &mut [F], size: usize) {
-        assert!(values.len().is_multiple_of(size));
-
-        #[cfg(not(feature = "parallel"))]
-        values.chunks_exact_mut(size).for_each(|values| {
-            values[1..].reverse();
-        });
-
-        #[cfg(feature = "parallel")]
-        values.par_chunks_exact_mut(size).for_each(|values| {
-            values[1..].reverse();
-        });
-
+        reverse_tails(values, size);
         self.ntt_batch(values, size);
     }
 
@@ -202,28 +249,9 @@ impl<F: Field> NttEngine<F> {
                 } else {
                     lcm(roots.len(), order)
                 };
-                roots.clear();
-                roots.reserve_exact(size);
 
                 // Compute powers of roots of unity.
-                let root = self.root(size);
-                #[cfg(not(feature = "parallel"))]
-                {
-                    let mut root_i = F::ONE;
-                    for _ in 0..size {
-                        roots.push(root_i);
-                        root_i *= root;
-                    }
-                }
-                #[cfg(feature = "parallel")]
-                roots.par_extend((0..size).into_par_iter().map_with(F::ZERO, |root_i, i| {
-                    if root_i.is_zero() {
-                        *root_i = root.pow([i as u64]);
-                    } else {
-                        *root_i *= root;
-                    }
-                    *root_i
-                }));
+                *roots = powers(self.root(size), size);
             }
             // Back to read lock.
             drop(roots);
@@ -736,6 +764,16 @@ mod tests {
         assert_eq!(engine1.root(engine1.order), expected_root);
     }
 
+    #[test]
+    fn test_generator_matches_engine() {
+        let engine = NttEngine::<Field64>::new_from_cache();
+        for size in 0..=1 << 10 {
+            assert_eq!(generator::<Field64>(size), engine.checked_root(size));
+        }
+        assert_eq!(generator::<Field64>(1 << 32), engine.checked_root(1 << 32));
+        assert_eq!(generator::<Field64>(1 << 33), None);
+    }
+
     #[test]
     fn test_ntt_batch_size_2() {
         let engine = NttEngine::<Field64>::new_from_fftfield();
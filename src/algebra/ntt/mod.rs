@@ -1,5 +1,7 @@
 //! NTT and related algorithms.
 
+mod additive;
+mod context;
 mod cooley_tukey;
 mod matrix;
 
@@ -23,12 +25,14 @@ use tracing::instrument;
 
 use self::matrix::MatrixMut;
 pub use self::{
+    additive::AdditiveNtt,
+    context::NttContext,
     cooley_tukey::{generator, intt, intt_batch, ntt, ntt_batch},
     transpose::transpose,
     wavelet::{inverse_wavelet_transform, wavelet_transform},
 };
 use crate::{
-    algebra::fields,
+    algebra::{fields, Basis},
     type_map::{self, TypeMap},
 };
 
@@ -40,13 +44,25 @@ pub static NTT: LazyLock<TypeMap<NttFamily>> = LazyLock::new(|| {
     map.insert(Arc::new(ArkNtt::<fields::Field256>::default()) as Arc<dyn ReedSolomon<_>>);
     map.insert(Arc::new(ArkNtt::<fields::Field64_2>::default()) as Arc<dyn ReedSolomon<_>>);
     map.insert(Arc::new(ArkNtt::<fields::Field64_3>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::BabyBear>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::BabyBear4>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::BabyBear5>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::KoalaBear>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::KoalaBear4>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::KoalaBear5>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(ArkNtt::<fields::Mersenne31Complex>::default()) as Arc<dyn ReedSolomon<_>>);
+    map.insert(Arc::new(AdditiveNtt::<fields::BinaryField8>::default()) as Arc<dyn ReedSolomon<_>>);
     map.insert(
-        Arc::new(ArkNtt::<<fields::Field64_2 as Field>::BasePrimeField>::default())
-            as Arc<dyn ReedSolomon<_>>,
+        Arc::new(AdditiveNtt::<fields::BinaryField16>::default()) as Arc<dyn ReedSolomon<_>>,
     );
     map.insert(
-        Arc::new(ArkNtt::<<fields::Field64_3 as Field>::BasePrimeField>::default())
-            as Arc<dyn ReedSolomon<_>>,
+        Arc::new(AdditiveNtt::<fields::BinaryField32>::default()) as Arc<dyn ReedSolomon<_>>,
+    );
+    map.insert(
+        Arc::new(AdditiveNtt::<fields::BinaryField64>::default()) as Arc<dyn ReedSolomon<_>>,
+    );
+    map.insert(
+        Arc::new(AdditiveNtt::<fields::BinaryField128>::default()) as Arc<dyn ReedSolomon<_>>
     );
     map
 });
@@ -60,12 +76,91 @@ impl type_map::Family for NttFamily {
 
 /// Trait for a Reed-Solomon encoder implementation for a given field `F`.
 pub trait ReedSolomon<F>: Debug + Send + Sync {
+    /// Encode messages given in `basis`, see [`interleaved_rs_encode`].
     fn interleaved_encode(
         &self,
         interleaved_coeffs: &[&[F]],
         expansion: usize,
         interleaving_depth: usize,
+        basis: Basis,
     ) -> Vec<F>;
+
+    /// Same as [`Self::interleaved_encode`] on the coset `coset_offset` of the evaluation
+    /// domain, writing the codewords to `output` and taking precomputed tables from `context`.
+    ///
+    /// The length of `output` must be `expansion` times the total length of the messages.
+    /// Implementations scale the coefficients of each padded block by [`scale_by_powers`]. The
+    /// default implementation scales copies of the messages and copies the result of
+    /// [`Self::interleaved_encode`].
+    #[allow(clippy::too_many_arguments)]
+    fn interleaved_encode_into(
+        &self,
+        _context: &NttContext,
+        interleaved_coeffs: &[&[F]],
+        expansion: usize,
+        interleaving_depth: usize,
+        basis: Basis,
+        coset_offset: F,
+        output: &mut [F],
+    ) where
+        F: Field,
+    {
+        if coset_offset == F::ONE {
+            output.copy_from_slice(&self.interleaved_encode(
+                interleaved_coeffs,
+                expansion,
+                interleaving_depth,
+                basis,
+            ));
+            return;
+        }
+        let shifted = interleaved_coeffs
+            .iter()
+            .map(|coeffs| {
+                let mut coeffs = coeffs.to_vec();
+                let block_size = coeffs.len() / interleaving_depth;
+                for block in coeffs.chunks_exact_mut(block_size) {
+                    if basis == Basis::Evaluations {
+                        inverse_wavelet_transform(block);
+                    }
+                    scale_by_powers(block, coset_offset);
+                }
+                coeffs
+            })
+            .collect::<Vec<_>>();
+        let shifted = shifted.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        output.copy_from_slice(&self.interleaved_encode(
+            &shifted,
+            expansion,
+            interleaving_depth,
+            Basis::Coefficients,
+        ));
+    }
+
+    /// Precompute the tables in `context` for blocks of `size` codeword symbols.
+    fn precompute(&self, _context: &NttContext, _size: usize) {}
+
+    /// Whether codewords of length `size` can be encoded.
+    ///
+    /// The default requires a root of unity of order `size`, see [`generator`].
+    fn supports(&self, size: usize) -> bool
+    where
+        F: FftField,
+    {
+        generator::<F>(size).is_some()
+    }
+
+    /// The point at which symbol `index` of codewords of length `size` is evaluated.
+    ///
+    /// The default is `ω^index` for the [`generator`] `ω` of order `size`.
+    fn evaluation_point(&self, size: usize, index: usize) -> F
+    where
+        F: FftField,
+    {
+        generator::<F>(size)
+            .expect("Subgroup of requested size not found")
+            .pow([index as u64])
+    }
 }
 
 assert_obj_safe!(ReedSolomon<crate::algebra::fields::Field256>);
@@ -79,18 +174,149 @@ impl<F: FftField> ReedSolomon<F> for ArkNtt<F> {
         interleaved_coeffs: &[&[F]],
         expansion: usize,
         interleaving_depth: usize,
+        basis: Basis,
     ) -> Vec<F> {
-        ark_ntt(interleaved_coeffs, expansion, interleaving_depth)
+        ark_ntt(interleaved_coeffs, expansion, interleaving_depth, basis)
     }
+
+    fn interleaved_encode_into(
+        &self,
+        context: &NttContext,
+        interleaved_coeffs: &[&[F]],
+        expansion: usize,
+        interleaving_depth: usize,
+        basis: Basis,
+        coset_offset: F,
+        output: &mut [F],
+    ) {
+        ark_ntt_into(
+            context,
+            interleaved_coeffs,
+            expansion,
+            interleaving_depth,
+            basis,
+            coset_offset,
+            output,
+        );
+    }
+
+    fn precompute(&self, context: &NttContext, size: usize) {
+        if generator::<F>(size).is_some() {
+            context.roots::<F>(size);
+        }
+    }
+}
+
+/// Register `encoder` for the field `F`, replacing any previously registered encoder.
+pub fn register<F: 'static>(encoder: Arc<dyn ReedSolomon<F>>) {
+    NTT.insert(encoder);
 }
 
-pub fn interleaved_rs_encode<F: 'static>(
+/// The Reed-Solomon encoder for `F`.
+///
+/// Fields without a registered encoder fall back to [`ArkNtt`], which is registered on first
+/// use.
+pub fn encoder<F: FftField>() -> Arc<dyn ReedSolomon<F>> {
+    NTT.get::<F>().unwrap_or_else(|| {
+        let encoder = Arc::new(ArkNtt::<F>::default()) as Arc<dyn ReedSolomon<F>>;
+        register(encoder.clone());
+        encoder
+    })
+}
+
+/// Precompute the tables of the encoder for `F` in `context` for blocks of `size` codeword
+/// symbols.
+pub fn precompute<F: FftField>(context: &NttContext, size: usize) {
+    encoder::<F>().precompute(context, size);
+}
+
+/// The point at which symbol `index` of codewords of length `size` over `F` is evaluated, see
+/// [`ReedSolomon::evaluation_point`].
+pub fn evaluation_point<F: FftField>(size: usize, index: usize) -> F {
+    encoder::<F>().evaluation_point(size, index)
+}
+
+/// Whether codewords of length `size` over `F` can be encoded, see
+/// [`ReedSolomon::supports`].
+///
+/// Fields without a registered encoder require a root of unity of order `size` for the
+/// [`ArkNtt`] fallback.
+pub fn supports<F: FftField>(size: usize) -> bool {
+    NTT.get::<F>().map_or_else(
+        || generator::<F>(size).is_some(),
+        |encoder| encoder.supports(size),
+    )
+}
+
+///
+/// RS encode coefficients grouped in `interleaving_depth` contiguous blocks
+/// at the rate 1/`expansion`, then interleave the evaluations per point.
+///
+/// With [`Basis::Evaluations`] each block is first converted to coefficients
+/// by the inverse wavelet transform, which requires power-of-two blocks.
+///
+/// Symbol `i` of a codeword is the evaluation at `coset_offset` times the
+/// [`evaluation_point`] `i`, i.e. on the coset `coset_offset · ⟨ω⟩` of the
+/// subgroup generated by [`generator`] for multiplicative encoders.
+///
+pub fn interleaved_rs_encode<F: FftField>(
     interleaved_coeffs: &[&[F]],
     expansion: usize,
     interleaving_depth: usize,
+    basis: Basis,
+    coset_offset: F,
 ) -> Vec<F> {
-    let engine = NTT.get::<F>().expect("Unsupported field");
-    engine.interleaved_encode(interleaved_coeffs, expansion, interleaving_depth)
+    let size = interleaved_coeffs.iter().map(|c| c.len()).sum::<usize>() * expansion;
+    let mut result = vec![F::ZERO; size];
+    interleaved_rs_encode_into(
+        NttContext::global(),
+        interleaved_coeffs,
+        expansion,
+        interleaving_depth,
+        basis,
+        coset_offset,
+        &mut result,
+    );
+    result
+}
+
+/// Same as [`interleaved_rs_encode`], writing the codewords to `output` and taking
+/// precomputed tables from `context`.
+///
+/// This allows reusing the allocation of previous codewords.
+pub fn interleaved_rs_encode_into<F: FftField>(
+    context: &NttContext,
+    interleaved_coeffs: &[&[F]],
+    expansion: usize,
+    interleaving_depth: usize,
+    basis: Basis,
+    coset_offset: F,
+    output: &mut [F],
+) {
+    encoder::<F>().interleaved_encode_into(
+        context,
+        interleaved_coeffs,
+        expansion,
+        interleaving_depth,
+        basis,
+        coset_offset,
+        output,
+    );
+}
+
+/// Multiply the coefficients `c_i` by `offset^i`.
+///
+/// Evaluating the result at `x` evaluates the original polynomial at `offset · x`, so this
+/// moves an encoder to the coset `offset` of its evaluation domain.
+pub fn scale_by_powers<F: Field>(coeffs: &mut [F], offset: F) {
+    if offset == F::ONE {
+        return;
+    }
+    let mut power = F::ONE;
+    for coeff in coeffs {
+        *coeff *= power;
+        power *= offset;
+    }
 }
 
 ///
@@ -100,11 +326,42 @@ pub fn interleaved_rs_encode<F: 'static>(
 /// This function computes the RS-code for each interleaved message and
 /// outputs the interleaved alphabets in the same order as the input.
 ///
-#[cfg_attr(feature = "tracing", instrument(level = "debug", skip(coeffs), fields(size = coeffs.len())))]
-fn ark_ntt<F: FftField>(coeffs: &[&[F]], expansion: usize, interleaving_depth: usize) -> Vec<F> {
+fn ark_ntt<F: FftField>(
+    coeffs: &[&[F]],
+    expansion: usize,
+    interleaving_depth: usize,
+    basis: Basis,
+) -> Vec<F> {
+    let size = coeffs.iter().map(|c| c.len()).sum::<usize>() * expansion;
+    let mut result = vec![F::ZERO; size];
+    ark_ntt_into(
+        NttContext::global(),
+        coeffs,
+        expansion,
+        interleaving_depth,
+        basis,
+        F::ONE,
+        &mut result,
+    );
+    result
+}
+
+/// Same as [`ark_ntt`] on the coset `coset_offset`, writing to `result` and taking the roots
+/// of unity from `context`.
+#[cfg_attr(feature = "tracing", instrument(level = "debug", skip(context, coeffs, result), fields(size = coeffs.len())))]
+fn ark_ntt_into<F: FftField>(
+    context: &NttContext,
+    coeffs: &[&[F]],
+    expansion: usize,
+    interleaving_depth: usize,
+    basis: Basis,
+    coset_offset: F,
+    result: &mut [F],
+) {
     assert!(expansion > 0);
     if coeffs.is_empty() {
-        return Vec::new();
+        assert!(result.is_empty());
+        return;
     }
 
     let poly_size = coeffs[0].len();
@@ -114,36 +371,158 @@ fn ark_ntt<F: FftField>(coeffs: &[&[F]], expansion: usize, interleaving_depth: u
     }
 
     let block_size = poly_size / interleaving_depth;
+    assert!(basis == Basis::Coefficients || block_size.is_power_of_two());
     let expanded_block = block_size * expansion;
     let per_poly_size = expanded_block * interleaving_depth;
-    let expanded_size = per_poly_size * coeffs.len();
+    assert_eq!(result.len(), per_poly_size * coeffs.len());
 
     // Lay out coefficients in contiguous blocks and zero-pad each block.
-    let mut result = vec![F::ZERO; expanded_size];
-    for (poly_index, poly) in coeffs.iter().enumerate() {
-        for (block_index, block) in poly.chunks_exact(block_size).enumerate() {
-            let dst = poly_index * per_poly_size + block_index * expanded_block;
-            result[dst..dst + block_size].copy_from_slice(block);
+    let blocks = coeffs.iter().flat_map(|poly| poly.chunks_exact(block_size));
+    for (dst, block) in result.chunks_exact_mut(expanded_block).zip(blocks) {
+        let (head, padding) = dst.split_at_mut(block_size);
+        head.copy_from_slice(block);
+        if basis == Basis::Evaluations {
+            inverse_wavelet_transform(head);
         }
+        scale_by_powers(head, coset_offset);
+        padding.fill(F::ZERO);
     }
 
     // NTT each block, then transpose to row-major order with vectorss
     // stacked horizontally.
-    ntt_batch(&mut result, expanded_block);
-    transpose(
-        &mut result,
-        coeffs.len() * interleaving_depth,
-        expanded_block,
-    );
-    result
+    context.ntt_batch(result, expanded_block);
+    transpose(result, coeffs.len() * interleaving_depth, expanded_block);
 }
 
 #[cfg(test)]
 mod tests {
-    use ark_ff::Field;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use ark_ff::{AdditiveGroup, Field, Fp64, MontBackend, MontConfig};
+    use ark_std::UniformRand;
 
     use super::*;
-    use crate::algebra::{fields::Field64, ntt::cooley_tukey::NttEngine};
+    use crate::algebra::{
+        fields::{
+            BabyBear, BinaryField1, BinaryField128, BinaryField8, Field64, Mersenne31,
+            Mersenne31Complex,
+        },
+        ntt::cooley_tukey::NttEngine,
+        univariate_evaluate,
+    };
+
+    /// A user-defined field without a built-in encoder.
+    #[derive(MontConfig)]
+    #[modulus = "193"]
+    #[generator = "5"]
+    pub struct Config193;
+    type Field193 = Fp64<MontBackend<Config193, 1>>;
+
+    /// Another user-defined field, for a custom encoder.
+    #[derive(MontConfig)]
+    #[modulus = "257"]
+    #[generator = "3"]
+    pub struct Config257;
+    type Field257 = Fp64<MontBackend<Config257, 1>>;
+
+    /// Encoder that counts its invocations.
+    #[derive(Debug, Default)]
+    struct CountingNtt(AtomicUsize);
+
+    impl<F: FftField> ReedSolomon<F> for CountingNtt {
+        fn interleaved_encode(
+            &self,
+            interleaved_coeffs: &[&[F]],
+            expansion: usize,
+            interleaving_depth: usize,
+            basis: Basis,
+        ) -> Vec<F> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            ark_ntt(interleaved_coeffs, expansion, interleaving_depth, basis)
+        }
+    }
+
+    #[test]
+    fn test_fallback_encoder() {
+        let mut rng = ark_std::test_rng();
+        let coeffs = (0..16)
+            .map(|_| Field193::rand(&mut rng))
+            .collect::<Vec<_>>();
+        assert!(!NTT.contains::<Field193>());
+        assert!(supports::<Field193>(64));
+        assert!(!supports::<Field193>(128));
+        let encoded = interleaved_rs_encode(
+            &[coeffs.as_slice()],
+            4,
+            2,
+            Basis::Coefficients,
+            Field193::ONE,
+        );
+        assert!(NTT.contains::<Field193>());
+        assert_eq!(
+            encoded,
+            ark_ntt(&[coeffs.as_slice()], 4, 2, Basis::Coefficients)
+        );
+    }
+
+    #[test]
+    fn test_register_encoder() {
+        let mut rng = ark_std::test_rng();
+        let coeffs = (0..16)
+            .map(|_| Field257::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let counting = Arc::new(CountingNtt::default());
+        register::<Field257>(counting.clone());
+        let encoded = interleaved_rs_encode(
+            &[coeffs.as_slice()],
+            2,
+            1,
+            Basis::Coefficients,
+            Field257::ONE,
+        );
+        assert_eq!(counting.0.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            encoded,
+            ark_ntt(&[coeffs.as_slice()], 2, 1, Basis::Coefficients)
+        );
+
+        // Encoders without their own `interleaved_encode_into` scale copies of the messages.
+        let mut expected = vec![Field257::ZERO; encoded.len()];
+        ark_ntt_into(
+            &NttContext::default(),
+            &[coeffs.as_slice()],
+            2,
+            1,
+            Basis::Coefficients,
+            Field257::GENERATOR,
+            &mut expected,
+        );
+        let encoded = interleaved_rs_encode(
+            &[coeffs.as_slice()],
+            2,
+            1,
+            Basis::Coefficients,
+            Field257::GENERATOR,
+        );
+        assert_eq!(counting.0.load(Ordering::SeqCst), 2);
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn test_supports() {
+        assert!(supports::<BabyBear>(1 << 27));
+        assert!(supports::<Mersenne31>(2));
+        assert!(!supports::<Mersenne31>(4));
+        assert!(supports::<Mersenne31Complex>(1 << 32));
+        // No even-order subgroup, and no registered encoder.
+        assert!(!supports::<BinaryField1>(2));
+        assert!(generator::<BinaryField1>(2).is_none());
+        // Subspaces of binary fields are bounded by the field size.
+        assert!(supports::<BinaryField8>(1 << 8));
+        assert!(!supports::<BinaryField8>(1 << 9));
+        assert!(!supports::<BinaryField8>(3));
+        assert!(supports::<BinaryField128>(1 << 40));
+    }
 
     #[test]
     fn test_expand_from_coeff_size_2() {
@@ -319,8 +698,6 @@ mod tests {
 
     #[test]
     fn test_interleaved_rs_encode() {
-        use ark_std::UniformRand;
-
         let mut rng = ark_std::test_rng();
         let count = 1 << 20;
         let expansion = 4;
@@ -343,8 +720,98 @@ mod tests {
         }
 
         // Compute things the new way
-        let interleaved_ntt =
-            interleaved_rs_encode(&[poly.as_slice()], expansion, 1 << folding_factor);
+        let interleaved_ntt = interleaved_rs_encode(
+            &[poly.as_slice()],
+            expansion,
+            1 << folding_factor,
+            Basis::Coefficients,
+            Field64::ONE,
+        );
         assert_eq!(expected, interleaved_ntt);
     }
+
+    #[test]
+    fn test_interleaved_rs_encode_evaluations() {
+        let mut rng = ark_std::test_rng();
+        let evals: Vec<_> = (0..1 << 10).map(|_| Field64::rand(&mut rng)).collect();
+        for (expansion, interleaving_depth) in [(2, 8), (4, 1), (3, 4)] {
+            let mut coeffs = evals.clone();
+            wavelet::inverse_wavelet_transform_batch(&mut coeffs, evals.len() / interleaving_depth);
+            assert_eq!(
+                interleaved_rs_encode(
+                    &[evals.as_slice()],
+                    expansion,
+                    interleaving_depth,
+                    Basis::Evaluations,
+                    Field64::ONE,
+                ),
+                interleaved_rs_encode(
+                    &[coeffs.as_slice()],
+                    expansion,
+                    interleaving_depth,
+                    Basis::Coefficients,
+                    Field64::ONE,
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_interleaved_rs_encode_coset() {
+        let mut rng = ark_std::test_rng();
+        let offset = Field64::GENERATOR;
+        let (expansion, interleaving_depth) = (4, 8);
+        let coeffs: Vec<_> = (0..1 << 8).map(|_| Field64::rand(&mut rng)).collect();
+        let block_size = coeffs.len() / interleaving_depth;
+        let root = generator::<Field64>(block_size * expansion).unwrap();
+        for basis in [Basis::Coefficients, Basis::Evaluations] {
+            let encoded = interleaved_rs_encode(
+                &[coeffs.as_slice()],
+                expansion,
+                interleaving_depth,
+                basis,
+                offset,
+            );
+            for (i, row) in encoded.chunks_exact(interleaving_depth).enumerate() {
+                let point = offset * root.pow([i as u64]);
+                for (block, value) in coeffs.chunks_exact(block_size).zip(row) {
+                    let mut block = block.to_vec();
+                    if basis == Basis::Evaluations {
+                        inverse_wavelet_transform(&mut block);
+                    }
+                    assert_eq!(univariate_evaluate(&block, point), *value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_interleaved_rs_encode_into() {
+        let mut rng = ark_std::test_rng();
+        let (expansion, interleaving_depth) = (4, 2);
+        let vectors: Vec<Vec<_>> = (0..3)
+            .map(|_| (0..1 << 6).map(|_| Field64::rand(&mut rng)).collect())
+            .collect();
+        let vectors = vectors.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        for basis in [Basis::Coefficients, Basis::Evaluations] {
+            for offset in [Field64::ONE, Field64::GENERATOR] {
+                let expected =
+                    interleaved_rs_encode(&vectors, expansion, interleaving_depth, basis, offset);
+                // Reused buffers contain stale codewords.
+                let mut output: Vec<_> = (0..expected.len())
+                    .map(|_| Field64::rand(&mut rng))
+                    .collect();
+                interleaved_rs_encode_into(
+                    &NttContext::default(),
+                    &vectors,
+                    expansion,
+                    interleaving_depth,
+                    basis,
+                    offset,
+                    &mut output,
+                );
+                assert_eq!(output, expected);
+            }
+        }
+    }
 }
@@ -1,37 +1,64 @@
-use ark_ff::Field;
+use std::ops::Range;
+
+use ark_ff::{batch_inversion, AdditiveGroup, FftField, Field};
 #[cfg(feature = "parallel")]
-use rayon::join;
+use rayon::{join, prelude::*};
 
-use crate::algebra::embedding::Embedding;
 #[cfg(feature = "parallel")]
 use crate::utils::workload_size;
+use crate::{
+    algebra::{
+        embedding::{Embedding, Identity},
+        geometric_sequence,
+        linear_form::LinearForm,
+        mixed_dot,
+        ntt::{self, NttContext},
+        tensor_product, MultilinearPoint,
+    },
+    utils::zip_strict,
+};
 
 /// Computes the constant and quadratic coefficient of the sumcheck polynomial.
 pub fn compute_sumcheck_polynomial<F: Field>(a: &[F], b: &[F]) -> (F, F) {
-    fn recurse<F: Field>(a0: &[F], a1: &[F], b0: &[F], b1: &[F]) -> (F, F) {
+    mixed_compute_sumcheck_polynomial(&Identity::<F>::new(), a, b)
+}
+
+/// Mixed field [`compute_sumcheck_polynomial`] with `a` in the source field.
+pub fn mixed_compute_sumcheck_polynomial<M: Embedding>(
+    embedding: &M,
+    a: &[M::Source],
+    b: &[M::Target],
+) -> (M::Target, M::Target) {
+    fn recurse<M: Embedding>(
+        embedding: &M,
+        a0: &[M::Source],
+        a1: &[M::Source],
+        b0: &[M::Target],
+        b1: &[M::Target],
+    ) -> (M::Target, M::Target) {
         debug_assert_eq!(a0.len(), a1.len());
         debug_assert_eq!(b0.len(), b1.len());
         debug_assert_eq!(a0.len(), b0.len());
 
         #[cfg(feature = "parallel")]
-        if a0.len() * 4 > workload_size::<F>() {
+        if a0.len() * 4 > workload_size::<M::Target>() {
             let mid = a0.len() / 2;
             let (a0l, a0r) = a0.split_at(mid);
             let (a1l, a1r) = a1.split_at(mid);
             let (b0l, b0r) = b0.split_at(mid);
             let (b1l, b1r) = b1.split_at(mid);
             let (left, right) = join(
-                || recurse(a0l, a1l, b0l, b1l),
-                || recurse(a0r, a1r, b0r, b1r),
+                || recurse(embedding, a0l, a1l, b0l, b1l),
+                || recurse(embedding, a0r, a1r, b0r, b1r),
             );
             return (left.0 + right.0, left.1 + right.1);
         }
 
-        let mut acc0 = F::ZERO;
-        let mut acc2 = F::ZERO;
+        let mut acc0 = M::Target::ZERO;
+        let mut acc2 = M::Target::ZERO;
         for ((&p0, &p1), (&eq0, &eq1)) in a0.iter().zip(a1).zip(b0.iter().zip(b1)) {
-            acc0 += p0 * eq0;
-            acc2 += (p1 - p0) * (eq1 - eq0);
+            acc0 += embedding.mixed_mul(eq0, p0);
+            acc2 += embedding.mixed_mul(eq1 - eq0, p1 - p0);
         }
         (acc0, acc2)
     }
@@ -41,7 +68,332 @@ pub fn compute_sumcheck_polynomial<F: Field>(a: &[F], b: &[F]) -> (F, F) {
 
     let (a0, a1) = a.split_at(half);
     let (b0, b1) = b.split_at(half);
-    recurse(a0, a1, b0, b1)
+    recurse(embedding, a0, a1, b0, b1)
+}
+
+/// A covector $w(x) = s · ∏_k ((1 - x_k) α_k + x_k β_k)$ kept in factored form.
+///
+/// This covers the equality polynomial $eq(z, ·)$ with $α_k = 1 - z_k$ and $β_k = z_k$, as
+/// well as univariate evaluations at $y$ with $α_k = 1$ and $β_k = y^(2^(n - 1 - k))$. The
+/// sumcheck prover only needs inner products with these, which can be computed from two
+/// tables of size $√n$ instead of the full covector of size $n$.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProductCovector<F: Field> {
+    pub scalar: F,
+    /// The pairs $(α_k, β_k)$, most significant variable first.
+    pub factors: Vec<(F, F)>,
+}
+
+impl<F: Field> ProductCovector<F> {
+    /// The covector $s · eq(z, ·)$.
+    pub fn eq(point: &[F], scalar: F) -> Self {
+        Self {
+            scalar,
+            factors: point.iter().map(|&z| (F::ONE - z, z)).collect(),
+        }
+    }
+
+    /// The covector $s · (1, y, y^2, …, y^(2^n - 1))$.
+    pub fn univariate(point: F, num_variables: usize, scalar: F) -> Self {
+        let mut factors = Vec::with_capacity(num_variables);
+        let mut power = point;
+        for _ in 0..num_variables {
+            factors.push((F::ONE, power));
+            power.square_in_place();
+        }
+        factors.reverse();
+        Self { scalar, factors }
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.factors.len()
+    }
+
+    /// Computes $⟨a, w⟩$ using two tables of size about $√n$.
+    pub fn dot(&self, a: &[F]) -> F {
+        self.mixed_dot(&Identity::<F>::new(), a)
+    }
+
+    /// Mixed field [`Self::dot`] with `a` in the source field.
+    pub fn mixed_dot<M: Embedding<Target = F>>(&self, embedding: &M, a: &[M::Source]) -> F {
+        assert_eq!(a.len(), 1 << self.num_variables());
+        let (high, low) = self.factors.split_at(self.num_variables() / 2);
+        let high = Self::expand(high, self.scalar);
+        let low = Self::expand(low, F::ONE);
+        zip_strict(a.chunks_exact(low.len()), high)
+            .map(|(chunk, weight)| weight * mixed_dot(embedding, &low, chunk))
+            .sum()
+    }
+
+    /// Fixes the most significant variable to `value`.
+    pub fn fold(&mut self, value: F) {
+        let (alpha, beta) = self.factors.remove(0);
+        self.scalar *= alpha + (beta - alpha) * value;
+    }
+
+    /// Computes `accumulator[i] += w_i`.
+    pub fn accumulate(&self, accumulator: &mut [F]) {
+        fn recurse<F: Field>(accumulator: &mut [F], factors: &[(F, F)], scalar: F) {
+            if let [(alpha, beta), rest @ ..] = factors {
+                let (acc_0, acc_1) = accumulator.split_at_mut(1 << rest.len());
+
+                #[cfg(feature = "parallel")]
+                if acc_0.len() > workload_size::<F>() {
+                    join(
+                        || recurse(acc_0, rest, scalar * alpha),
+                        || recurse(acc_1, rest, scalar * beta),
+                    );
+                    return;
+                }
+
+                recurse(acc_0, rest, scalar * alpha);
+                recurse(acc_1, rest, scalar * beta);
+            } else {
+                accumulator[0] += scalar;
+            }
+        }
+
+        assert_eq!(accumulator.len(), 1 << self.num_variables());
+        recurse(accumulator, &self.factors, self.scalar);
+    }
+
+    fn expand(factors: &[(F, F)], scalar: F) -> Vec<F> {
+        factors.iter().fold(vec![scalar], |acc, &(alpha, beta)| {
+            tensor_product(&acc, &[alpha, beta])
+        })
+    }
+}
+
+/// Computes the constant and quadratic coefficient of the sumcheck polynomial for
+/// $⟨a, ∑_j w_j⟩$ with the $w_j$ in factored form.
+///
+/// The `sum` is used to avoid one of the inner products.
+pub fn compute_product_sumcheck_polynomial<F: Field>(
+    a: &[F],
+    covectors: &[ProductCovector<F>],
+    sum: F,
+) -> (F, F) {
+    mixed_compute_product_sumcheck_polynomial(&Identity::<F>::new(), a, covectors, sum)
+}
+
+/// Mixed field [`compute_product_sumcheck_polynomial`] with `a` in the source field.
+pub fn mixed_compute_product_sumcheck_polynomial<M: Embedding>(
+    embedding: &M,
+    a: &[M::Source],
+    covectors: &[ProductCovector<M::Target>],
+    sum: M::Target,
+) -> (M::Target, M::Target) {
+    let (low, high) = a.split_at(a.len() / 2);
+    // For each term the linear factor (α, β) and the restriction q(X) to the remaining variables.
+    let terms = covectors
+        .iter()
+        .map(|covector| {
+            let (&(alpha, beta), factors) = covector.factors.split_first().expect("No variables");
+            let rest = ProductCovector {
+                scalar: covector.scalar,
+                factors: factors.to_vec(),
+            };
+            (alpha, beta, rest.mixed_dot(embedding, low), rest)
+        })
+        .collect::<Vec<_>>();
+    let c0 = terms
+        .iter()
+        .map(|(alpha, _, q0, _)| *alpha * q0)
+        .sum::<M::Target>();
+
+    // Recover q(1) of the first term from the sum, if possible.
+    let mut remainder = sum - c0;
+    let mut c2 = M::Target::ZERO;
+    for (alpha, beta, q0, rest) in terms.iter().skip(1) {
+        let q1 = rest.mixed_dot(embedding, high);
+        remainder -= *beta * q1;
+        c2 += (*beta - *alpha) * (q1 - *q0);
+    }
+    if let Some((alpha, beta, q0, rest)) = terms.first() {
+        let q1 = beta.inverse().map_or_else(
+            || rest.mixed_dot(embedding, high),
+            |inverse| remainder * inverse,
+        );
+        c2 += (*beta - *alpha) * (q1 - *q0);
+    }
+    (c0, c2)
+}
+
+/// Evaluations at $0, 2, 3, …, degree$ of the composite sumcheck polynomial.
+///
+/// This is $p(X) = ∑_x f(a_1(X, x), …, a_k(X, x))$ where $X$ is the most significant
+/// variable and $f$ is `composition`, of total degree at most `degree`. The evaluation at
+/// one is omitted as it follows from the sum.
+pub fn compute_composite_polynomial<F, C>(
+    inputs: &[Vec<F>],
+    degree: usize,
+    composition: &C,
+) -> Vec<F>
+where
+    F: Field,
+    C: Fn(&[F]) -> F + Sync,
+{
+    fn recurse<F, C>(
+        inputs: &[Vec<F>],
+        range: Range<usize>,
+        degree: usize,
+        composition: &C,
+    ) -> Vec<F>
+    where
+        F: Field,
+        C: Fn(&[F]) -> F + Sync,
+    {
+        #[cfg(feature = "parallel")]
+        if range.len() * inputs.len() * degree > workload_size::<F>() {
+            let mid = range.start + range.len() / 2;
+            let (left, right) = join(
+                || recurse(inputs, range.start..mid, degree, composition),
+                || recurse(inputs, mid..range.end, degree, composition),
+            );
+            return zip_strict(left, right).map(|(l, r)| l + r).collect();
+        }
+
+        let half = inputs[0].len() / 2;
+        let mut acc = vec![F::ZERO; degree];
+        let mut values = vec![F::ZERO; inputs.len()];
+        let mut slopes = vec![F::ZERO; inputs.len()];
+        for i in range {
+            for ((value, slope), input) in values.iter_mut().zip(&mut slopes).zip(inputs) {
+                *value = input[i];
+                *slope = input[half + i] - input[i];
+            }
+            acc[0] += composition(&values);
+
+            // Step to X = 1, then evaluate at X = 2, 3, …, degree.
+            for (value, slope) in values.iter_mut().zip(&slopes) {
+                *value += *slope;
+            }
+            for entry in &mut acc[1..] {
+                for (value, slope) in values.iter_mut().zip(&slopes) {
+                    *value += *slope;
+                }
+                *entry += composition(&values);
+            }
+        }
+        acc
+    }
+
+    assert!(degree >= 1);
+    assert!(!inputs.is_empty());
+    let size = inputs[0].len();
+    assert!(size.is_multiple_of(2));
+    assert!(inputs.iter().all(|input| input.len() == size));
+    recurse(inputs, 0..size / 2, degree, composition)
+}
+
+/// Mixed field [`compute_composite_polynomial`] of $∑_x w(x) · f(a_1(x), …, a_k(x))$ with
+/// the weight $w$ in factored form and the inputs in the source field.
+///
+/// Only tables of size about $√n$ of $w$ are stored. The composition $f$ must have total
+/// degree at most `degree - 1`, as the linear factor of $w$ in $X$ adds one.
+pub fn mixed_compute_product_composite_polynomial<M, C>(
+    embedding: &M,
+    weight: &ProductCovector<M::Target>,
+    inputs: &[&[M::Source]],
+    degree: usize,
+    composition: &C,
+) -> Vec<M::Target>
+where
+    M: Embedding,
+    C: Fn(&[M::Target]) -> M::Target + Sync,
+{
+    fn recurse<M, C>(
+        embedding: &M,
+        inputs: &[&[M::Source]],
+        (high, low): (&[M::Target], &[M::Target]),
+        range: Range<usize>,
+        degree: usize,
+        composition: &C,
+    ) -> Vec<M::Target>
+    where
+        M: Embedding,
+        C: Fn(&[M::Target]) -> M::Target + Sync,
+    {
+        #[cfg(feature = "parallel")]
+        if range.len() * inputs.len() * degree > workload_size::<M::Target>() {
+            let mid = range.start + range.len() / 2;
+            let (left, right) = join(
+                || {
+                    recurse(
+                        embedding,
+                        inputs,
+                        (high, low),
+                        range.start..mid,
+                        degree,
+                        composition,
+                    )
+                },
+                || {
+                    recurse(
+                        embedding,
+                        inputs,
+                        (high, low),
+                        mid..range.end,
+                        degree,
+                        composition,
+                    )
+                },
+            );
+            return zip_strict(left, right).map(|(l, r)| l + r).collect();
+        }
+
+        let half = inputs[0].len() / 2;
+        let mut acc = vec![M::Target::ZERO; degree];
+        let mut values = vec![M::Target::ZERO; inputs.len()];
+        let mut slopes = vec![M::Target::ZERO; inputs.len()];
+        for i in range {
+            let weight = high[i / low.len()] * low[i % low.len()];
+            for ((value, slope), input) in values.iter_mut().zip(&mut slopes).zip(inputs) {
+                *value = embedding.map(input[i]);
+                *slope = embedding.map(input[half + i] - input[i]);
+            }
+            acc[0] += weight * composition(&values);
+
+            // Step to X = 1, then evaluate at X = 2, 3, …, degree.
+            for (value, slope) in values.iter_mut().zip(&slopes) {
+                *value += *slope;
+            }
+            for entry in &mut acc[1..] {
+                for (value, slope) in values.iter_mut().zip(&slopes) {
+                    *value += *slope;
+                }
+                *entry += weight * composition(&values);
+            }
+        }
+        acc
+    }
+
+    assert!(degree >= 1);
+    assert!(!inputs.is_empty());
+    let size = 1 << weight.num_variables();
+    assert!(inputs.iter().all(|input| input.len() == size));
+    let (&(alpha, beta), rest) = weight.factors.split_first().expect("No variables");
+    let (high, low) = rest.split_at(rest.len() / 2);
+    let high = ProductCovector::expand(high, weight.scalar);
+    let low = ProductCovector::expand(low, M::Target::ONE);
+    let mut evaluations = recurse(
+        embedding,
+        inputs,
+        (&high, &low),
+        0..size / 2,
+        degree,
+        composition,
+    );
+
+    // Multiply by the linear factor α + (β - α) X of the weight.
+    let mut factor = alpha;
+    evaluations[0] *= factor;
+    factor += beta - alpha;
+    for evaluation in &mut evaluations[1..] {
+        factor += beta - alpha;
+        *evaluation *= factor;
+    }
+    evaluations
 }
 
 /// Folds evaluations by linear interpolation at the given weight, in place.
@@ -70,6 +422,210 @@ pub fn fold<F: Field>(values: &mut Vec<F>, weight: F) {
     values.shrink_to_fit();
 }
 
+/// Folds source field evaluations by linear interpolation at a target field weight.
+///
+/// Mixed field [`fold`] that returns the folded values in the target field.
+pub fn mixed_fold<M: Embedding>(
+    embedding: &M,
+    values: &[M::Source],
+    weight: M::Target,
+) -> Vec<M::Target> {
+    assert!(values.len().is_multiple_of(2));
+    let (low, high) = values.split_at(values.len() / 2);
+
+    #[cfg(not(feature = "parallel"))]
+    let result = zip_strict(low, high)
+        .map(|(&low, &high)| embedding.mixed_add(embedding.mixed_mul(weight, high - low), low))
+        .collect();
+
+    #[cfg(feature = "parallel")]
+    let result = low
+        .par_iter()
+        .zip(high)
+        .map(|(&low, &high)| embedding.mixed_add(embedding.mixed_mul(weight, high - low), low))
+        .collect();
+
+    result
+}
+
+/// Folds blocks of evaluations with the given weights.
+///
+/// Splits `values` into `weights.len()` blocks, most significant first, and returns
+/// their linear combination. For the weights `(1 - w, w)` this is [`fold`] at `w`.
+pub fn fold_weighted<F: Field>(values: &mut Vec<F>, weights: &[F]) {
+    *values = mixed_fold_weighted(&Identity::<F>::new(), values, weights);
+}
+
+/// Mixed field [`fold_weighted`] that returns the folded values in the target field.
+pub fn mixed_fold_weighted<M: Embedding>(
+    embedding: &M,
+    values: &[M::Source],
+    weights: &[M::Target],
+) -> Vec<M::Target> {
+    assert!(!weights.is_empty() && values.len().is_multiple_of(weights.len()));
+    let size = values.len() / weights.len();
+    let fold_at = |index: usize| {
+        weights
+            .iter()
+            .enumerate()
+            .map(|(block, &weight)| embedding.mixed_mul(weight, values[block * size + index]))
+            .sum::<M::Target>()
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    let result = (0..size).map(fold_at).collect();
+
+    #[cfg(feature = "parallel")]
+    let result = (0..size).into_par_iter().map(fold_at).collect();
+
+    result
+}
+
+/// The Lagrange basis of the multiplicative subgroup of order `size`, evaluated at `point`.
+///
+/// The basis is ordered by the subgroup elements $ω^j$ where $ω$ is [`ntt::generator`].
+pub fn lagrange_weights<F: FftField>(size: usize, point: F) -> Vec<F> {
+    let generator = ntt::generator::<F>(size).expect("Subgroup of requested order does not exist.");
+    let subgroup = geometric_sequence(generator, size);
+    if let Some(index) = subgroup.iter().position(|&element| element == point) {
+        let mut weights = vec![F::ZERO; size];
+        weights[index] = F::ONE;
+        return weights;
+    }
+
+    // L_j(X) = ω^j (X^m - 1) / (m (X - ω^j))
+    let scale = (point.pow([size as u64]) - F::ONE) / F::from(size as u64);
+    let mut denominators = subgroup
+        .iter()
+        .map(|&element| point - element)
+        .collect::<Vec<_>>();
+    batch_inversion(&mut denominators);
+    zip_strict(subgroup, denominators)
+        .map(|(element, inverse)| scale * element * inverse)
+        .collect()
+}
+
+/// Coefficients of the univariate skip polynomial over the subgroup of order `domain_size`.
+///
+/// Splits `a` and `b` into `domain_size` blocks and interpolates each column $x$ over the
+/// subgroup as $A_x(X)$ and $B_x(X)$. The result is $p(X) = ∑_x A_x(X) B_x(X)$ of degree
+/// `2 · domain_size - 2`, whose sum over the subgroup is `dot(a, b)`.
+///
+/// The interpolation uses the roots of unity retained in `context`.
+pub fn compute_skip_polynomial<F: FftField>(
+    context: &NttContext,
+    domain_size: usize,
+    a: &[F],
+    b: &[F],
+) -> Vec<F> {
+    mixed_compute_skip_polynomial(context, &Identity::<F>::new(), domain_size, a, b)
+}
+
+/// Mixed field [`compute_skip_polynomial`] with `a` in the source field.
+pub fn mixed_compute_skip_polynomial<M>(
+    context: &NttContext,
+    embedding: &M,
+    domain_size: usize,
+    a: &[M::Source],
+    b: &[M::Target],
+) -> Vec<M::Target>
+where
+    M: Embedding,
+    M::Target: FftField,
+{
+    fn recurse<M: Embedding>(
+        embedding: &M,
+        a: &[M::Source],
+        b: &[M::Target],
+        range: Range<usize>,
+        coset_weights: &[Vec<M::Target>],
+    ) -> Vec<M::Target> {
+        let domain_size = coset_weights.len();
+
+        #[cfg(feature = "parallel")]
+        if range.len() * domain_size * domain_size > workload_size::<M::Target>() {
+            let mid = range.start + range.len() / 2;
+            let (left, right) = join(
+                || recurse(embedding, a, b, range.start..mid, coset_weights),
+                || recurse(embedding, a, b, mid..range.end, coset_weights),
+            );
+            return zip_strict(left, right).map(|(l, r)| l + r).collect();
+        }
+
+        let stride = a.len() / domain_size;
+        let mut acc = vec![M::Target::ZERO; 2 * domain_size];
+        for column in range {
+            // The subgroup is the even powers of the generator of order `2 · domain_size`.
+            for block in 0..domain_size {
+                let index = block * stride + column;
+                acc[2 * block] += embedding.mixed_mul(b[index], a[index]);
+            }
+            // Its coset is the odd powers.
+            for (block, weights) in coset_weights.iter().enumerate() {
+                let mut a_value = M::Target::ZERO;
+                let mut b_value = M::Target::ZERO;
+                for (row, &weight) in weights.iter().enumerate() {
+                    let index = row * stride + column;
+                    a_value += embedding.mixed_mul(weight, a[index]);
+                    b_value += weight * b[index];
+                }
+                acc[2 * block + 1] += a_value * b_value;
+            }
+        }
+        acc
+    }
+
+    assert_eq!(a.len(), b.len());
+    assert!(domain_size >= 2 && a.len().is_multiple_of(domain_size));
+    let generator = ntt::generator::<M::Target>(2 * domain_size)
+        .expect("Subgroup of requested order does not exist.");
+    let coset_weights = geometric_sequence(generator.square(), domain_size)
+        .into_iter()
+        .map(|element| lagrange_weights(domain_size, generator * element))
+        .collect::<Vec<_>>();
+    let mut evaluations = recurse(embedding, a, b, 0..a.len() / domain_size, &coset_weights);
+
+    // Interpolate over the subgroup of order `2 · domain_size`.
+    context.intt(&mut evaluations);
+    let scale = M::Target::from((2 * domain_size) as u64)
+        .inverse()
+        .expect("Characteristic too small");
+    debug_assert_eq!(evaluations.last(), Some(&M::Target::ZERO));
+    evaluations.truncate(2 * domain_size - 1);
+    for coefficient in &mut evaluations {
+        *coefficient *= scale;
+    }
+    evaluations
+}
+
+/// Evaluates `linear_form` at a folding point that starts with a univariate skip.
+///
+/// The first `univariate_skip` variables are replaced by the single coordinate `point[0]`
+/// over the subgroup of order `2^univariate_skip`, folded with [`lagrange_weights`].
+/// Without skip this is [`LinearForm::mle_evaluate`].
+pub fn univariate_skip_evaluate<F: FftField>(
+    linear_form: &(impl LinearForm<F> + ?Sized),
+    univariate_skip: usize,
+    point: &[F],
+) -> F {
+    if univariate_skip == 0 {
+        return linear_form.mle_evaluate(point);
+    }
+    let (&skip_point, rest) = point.split_first().expect("Missing univariate skip point");
+    let mut hypercube_point = vec![F::ZERO; univariate_skip];
+    hypercube_point.extend_from_slice(rest);
+    lagrange_weights(1 << univariate_skip, skip_point)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, weight)| *weight != F::ZERO)
+        .map(|(index, weight)| {
+            let block = MultilinearPoint::<F>::from_index(index, univariate_skip);
+            hypercube_point[..univariate_skip].copy_from_slice(&block.0);
+            weight * linear_form.mle_evaluate(&hypercube_point)
+        })
+        .sum()
+}
+
 /// Evaluate a coefficient vector at a multilinear point in the target field.
 pub fn mixed_eval<M: Embedding>(
     embedding: &M,
@@ -5,13 +5,13 @@ use std::{
     time::{Duration, Instant},
 };
 
-use ark_ff::{FftField, Field};
+use ark_ff::FftField;
 use ark_serialize::CanonicalSerialize;
 use clap::Parser;
 use serde::Serialize;
 use whir::{
     algebra::{
-        embedding::Basefield,
+        embedding::{Basefield, Embedding, Identity},
         fields,
         linear_form::{Evaluate, LinearForm, MultilinearExtension},
         MultilinearPoint,
@@ -100,19 +100,29 @@ fn main() {
 
     // Type reflection on field
     match field {
-        AvailableFields::Goldilocks1 => run_whir::<fields::Field64>(&args),
-        AvailableFields::Goldilocks2 => run_whir::<fields::Field64_2>(&args),
-        AvailableFields::Goldilocks3 => run_whir::<fields::Field64_3>(&args),
-        AvailableFields::Field128 => run_whir::<fields::Field128>(&args),
-        AvailableFields::Field192 => run_whir::<fields::Field192>(&args),
-        AvailableFields::Field256 => run_whir::<fields::Field256>(&args),
+        AvailableFields::Goldilocks1 => run_whir(&args, Basefield::<fields::Field64>::new()),
+        AvailableFields::Goldilocks2 => run_whir(&args, Basefield::<fields::Field64_2>::new()),
+        AvailableFields::Goldilocks3 => run_whir(&args, Basefield::<fields::Field64_3>::new()),
+        AvailableFields::Field128 => run_whir(&args, Basefield::<fields::Field128>::new()),
+        AvailableFields::Field192 => run_whir(&args, Basefield::<fields::Field192>::new()),
+        AvailableFields::Field256 => run_whir(&args, Basefield::<fields::Field256>::new()),
+        AvailableFields::BabyBear4 => run_whir(&args, Basefield::<fields::BabyBear4>::new()),
+        AvailableFields::KoalaBear4 => run_whir(&args, Basefield::<fields::KoalaBear4>::new()),
+        AvailableFields::BabyBear5 => run_whir(&args, Basefield::<fields::BabyBear5>::new()),
+        AvailableFields::KoalaBear5 => run_whir(&args, Basefield::<fields::KoalaBear5>::new()),
+        // The base field Mersenne31 has no NTT, so commit over the complex extension itself.
+        AvailableFields::Mersenne31Complex => {
+            run_whir(&args, Identity::<fields::Mersenne31Complex>::new());
+        }
     }
 }
 
 #[allow(clippy::too_many_lines)]
-fn run_whir<F>(args: &Args)
+fn run_whir<F, M>(args: &Args, embedding: M)
 where
     F: FftField + CanonicalSerialize + Codec,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
 {
     let security_level = args.security_level;
     let pow_bits = args.pow_bits.unwrap();
@@ -143,9 +153,7 @@ where
         hash_id: args.hash.hash_id(),
     };
 
-    let vector = (0..num_coeffs)
-        .map(<F as Field>::BasePrimeField::from)
-        .collect::<Vec<_>>();
+    let vector = (0..num_coeffs).map(M::Source::from).collect::<Vec<_>>();
 
     let (
         whir_ldt_prover_time,
@@ -161,7 +169,7 @@ where
             initial_statement: false,
             ..whir_params
         };
-        let params = Config::<F>::new(mv_params, &whir_params);
+        let params = Config::with_embedding(embedding.clone(), mv_params, &whir_params);
         if !params.check_max_pow_bits(Bits::new(whir_params.pow_bits as f64)) {
             println!("WARN: more PoW bits required than what specified.");
         }
@@ -206,6 +214,8 @@ where
                     &weight_refs,
                     &evaluations,
                 )
+                .unwrap()
+                .check_with(&weight_refs)
                 .unwrap();
         }
 
@@ -231,7 +241,7 @@ where
         // Run PCS
         use whir::protocols::whir::Config;
 
-        let params = Config::<F>::new(mv_params, &whir_params);
+        let params = Config::with_embedding(embedding, mv_params, &whir_params);
         if !params.check_max_pow_bits(Bits::new(whir_params.pow_bits as f64)) {
             println!("WARN: more PoW bits required than what specified.");
         }
@@ -246,7 +256,7 @@ where
             .map(|i| MultilinearPoint(vec![F::from(i as u64); num_variables]))
             .collect();
 
-        let mut weights: Vec<Box<dyn Evaluate<Basefield<F>>>> = Vec::new();
+        let mut weights: Vec<Box<dyn Evaluate<M>>> = Vec::new();
         let mut evaluations = Vec::new();
 
         for point in &points {
@@ -296,6 +306,8 @@ where
                     &weight_refs,
                     &evaluations,
                 )
+                .unwrap()
+                .check_with(&weight_refs)
                 .unwrap();
         }
 
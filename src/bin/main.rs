@@ -1,11 +1,11 @@
 use std::{borrow::Cow, time::Instant};
 
-use ark_ff::{FftField, Field};
+use ark_ff::FftField;
 use ark_serialize::CanonicalSerialize;
 use clap::Parser;
 use whir::{
     algebra::{
-        embedding::Basefield,
+        embedding::{Basefield, Embedding, Identity},
         fields,
         linear_form::{Covector, Evaluate, LinearForm, MultilinearExtension},
         MultilinearPoint,
@@ -76,32 +76,44 @@ fn main() {
 fn runner(args: &Args, field: AvailableFields) {
     // Type reflection on field
     match field {
-        AvailableFields::Goldilocks1 => run_whir::<fields::Field64>(args),
-        AvailableFields::Goldilocks2 => run_whir::<fields::Field64_2>(args),
-        AvailableFields::Goldilocks3 => run_whir::<fields::Field64_3>(args),
-        AvailableFields::Field128 => run_whir::<fields::Field128>(args),
-        AvailableFields::Field192 => run_whir::<fields::Field192>(args),
-        AvailableFields::Field256 => run_whir::<fields::Field256>(args),
+        AvailableFields::Goldilocks1 => run_whir(args, Basefield::<fields::Field64>::new()),
+        AvailableFields::Goldilocks2 => run_whir(args, Basefield::<fields::Field64_2>::new()),
+        AvailableFields::Goldilocks3 => run_whir(args, Basefield::<fields::Field64_3>::new()),
+        AvailableFields::Field128 => run_whir(args, Basefield::<fields::Field128>::new()),
+        AvailableFields::Field192 => run_whir(args, Basefield::<fields::Field192>::new()),
+        AvailableFields::Field256 => run_whir(args, Basefield::<fields::Field256>::new()),
+        AvailableFields::BabyBear4 => run_whir(args, Basefield::<fields::BabyBear4>::new()),
+        AvailableFields::KoalaBear4 => run_whir(args, Basefield::<fields::KoalaBear4>::new()),
+        AvailableFields::BabyBear5 => run_whir(args, Basefield::<fields::BabyBear5>::new()),
+        AvailableFields::KoalaBear5 => run_whir(args, Basefield::<fields::KoalaBear5>::new()),
+        // The base field Mersenne31 has no NTT, so commit over the complex extension itself.
+        AvailableFields::Mersenne31Complex => {
+            run_whir(args, Identity::<fields::Mersenne31Complex>::new());
+        }
     }
 }
 
-fn run_whir<F>(args: &Args)
+fn run_whir<F, M>(args: &Args, embedding: M)
 where
     F: FftField + CanonicalSerialize + Codec,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
 {
     match args.protocol_type {
         WhirType::PCS => {
-            run_whir_pcs::<F>(args);
+            run_whir_pcs(args, embedding);
         }
         WhirType::LDT => {
-            run_whir_as_ldt::<F>(args);
+            run_whir_as_ldt(args, embedding);
         }
     }
 }
 
-fn run_whir_as_ldt<F>(args: &Args)
+fn run_whir_as_ldt<F, M>(args: &Args, embedding: M)
 where
     F: FftField + CanonicalSerialize + Codec,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
 {
     use whir::protocols::whir::Config;
 
@@ -138,7 +150,7 @@ where
         hash_id,
     };
 
-    let params = Config::<F>::new(mv_params, &whir_params);
+    let params = Config::with_embedding(embedding, mv_params, &whir_params);
 
     let ds = DomainSeparator::protocol(&params)
         .session(&format!("Example at {}:{}", file!(), line!()))
@@ -150,25 +162,22 @@ where
     println!("Whir (LDT) 🌪️");
     println!("Field: {:?} and hash: {:?}", args.field, args.hash);
     println!("{params}");
+    println!("Proximity: {:.3}", params.proximity());
     if !params.check_max_pow_bits(Bits::new(whir_params.pow_bits as f64)) {
         println!("WARN: more PoW bits required than what specified.");
     }
 
-    let vector = (0..num_coeffs)
-        .map(<F as Field>::BasePrimeField::from)
-        .collect::<Vec<_>>();
+    let vector = (0..num_coeffs).map(M::Source::from).collect::<Vec<_>>();
 
     let whir_commit_time = Instant::now();
     let witness = params.commit(&mut prover_state, &[&vector]);
     let whir_commit_time = whir_commit_time.elapsed();
 
     let whir_prove_time = Instant::now();
-    params.prove(
+    params.prove_proximity(
         &mut prover_state,
         vec![Cow::from(vector)],
         vec![Cow::Owned(witness)],
-        vec![],
-        Cow::Owned(vec![]),
     );
     let whir_prove_time = whir_prove_time.elapsed();
 
@@ -188,7 +197,7 @@ where
 
         let commitment = params.receive_commitment(&mut verifier_state).unwrap();
         params
-            .verify(&mut verifier_state, &[&commitment], &[], &[])
+            .verify_proximity(&mut verifier_state, &[&commitment])
             .unwrap();
     }
     dbg!(whir_verifier_time.elapsed() / reps as u32);
@@ -196,9 +205,11 @@ where
 }
 
 #[allow(clippy::too_many_lines)]
-fn run_whir_pcs<F>(args: &Args)
+fn run_whir_pcs<F, M>(args: &Args, embedding: M)
 where
     F: FftField + CanonicalSerialize + Codec,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
 {
     use whir::protocols::whir::Config;
 
@@ -237,7 +248,7 @@ where
         hash_id,
     };
 
-    let params = Config::<F>::new(mv_params, &whir_params);
+    let params = Config::with_embedding(embedding, mv_params, &whir_params);
 
     let ds = DomainSeparator::protocol(&params)
         .session(&format!("Example at {}:{}", file!(), line!()))
@@ -253,15 +264,13 @@ where
         println!("WARN: more PoW bits required than what specified.");
     }
 
-    let vector = (0..num_coeffs)
-        .map(<F as Field>::BasePrimeField::from)
-        .collect::<Vec<_>>();
+    let vector = (0..num_coeffs).map(M::Source::from).collect::<Vec<_>>();
 
     let whir_commit_time = Instant::now();
     let witness = params.commit(&mut prover_state, &[&vector]);
     let whir_commit_time = whir_commit_time.elapsed();
 
-    let mut linear_forms: Vec<Box<dyn Evaluate<Basefield<F>>>> = Vec::new();
+    let mut linear_forms: Vec<Box<dyn Evaluate<M>>> = Vec::new();
     let mut evaluations = Vec::new();
 
     // Evaluation constraint
@@ -335,6 +344,8 @@ where
                 &weight_dyn_refs,
                 &evaluations,
             )
+            .unwrap()
+            .check_with(&weight_dyn_refs)
             .unwrap();
     }
     println!(
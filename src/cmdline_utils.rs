@@ -24,12 +24,17 @@ impl FromStr for WhirType {
 
 #[derive(Debug, Clone, Copy, Serialize)]
 pub enum AvailableFields {
-    Goldilocks1, // Just Goldilocks
-    Goldilocks2, // Quadratic extension of Goldilocks
-    Goldilocks3, // Cubic extension of Goldilocks
-    Field128,    // 128-bit prime field
-    Field192,    // 192-bit prime field
-    Field256,    // 256-bit prime field
+    Goldilocks1,       // Just Goldilocks
+    Goldilocks2,       // Quadratic extension of Goldilocks
+    Goldilocks3,       // Cubic extension of Goldilocks
+    Field128,          // 128-bit prime field
+    Field192,          // 192-bit prime field
+    Field256,          // 256-bit prime field
+    BabyBear4,         // Quartic extension of BabyBear
+    KoalaBear4,        // Quartic extension of KoalaBear
+    BabyBear5,         // Quintic extension of BabyBear
+    KoalaBear5,        // Quintic extension of KoalaBear
+    Mersenne31Complex, // Complex extension of Mersenne31
 }
 
 impl FromStr for AvailableFields {
@@ -43,6 +48,11 @@ impl FromStr for AvailableFields {
             "Goldilocks1" => Ok(Self::Goldilocks1),
             "Goldilocks2" => Ok(Self::Goldilocks2),
             "Goldilocks3" => Ok(Self::Goldilocks3),
+            "BabyBear4" => Ok(Self::BabyBear4),
+            "KoalaBear4" => Ok(Self::KoalaBear4),
+            "BabyBear5" => Ok(Self::BabyBear5),
+            "KoalaBear5" => Ok(Self::KoalaBear5),
+            "Mersenne31Complex" => Ok(Self::Mersenne31Complex),
             _ => Err(format!("Invalid field: {s}")),
         }
     }
@@ -35,9 +35,9 @@ use crate::{
         dot,
         embedding::{Basefield, Embedding, Identity},
         lift,
-        linear_form::UnivariateEvaluation,
-        mixed_univariate_evaluate,
-        ntt::{self, interleaved_rs_encode},
+        linear_form::{Evaluate, UnivariateEvaluation},
+        ntt::{self, interleaved_rs_encode_into, NttContext},
+        Basis,
     },
     hash::Hash,
     protocols::{challenge_indices::challenge_indices, matrix_commit},
@@ -82,6 +82,21 @@ where
     /// The number of independent codewords that are interleaved together.
     pub interleaving_depth: usize,
 
+    /// The basis the vectors are given in.
+    ///
+    /// For [`Basis::Evaluations`] the vector size and interleaving depth must be powers of two.
+    #[serde(default)]
+    pub basis: Basis,
+
+    /// The codewords are evaluated at the evaluation points of the encoder scaled by
+    /// `coset_offset`, i.e. symbol `j` is evaluated at
+    /// `coset_offset · ntt::evaluation_point(num_rows, j)`, see [`ntt::evaluation_point`].
+    ///
+    /// A non-trivial offset keeps the in-domain points disjoint from the subgroup, which
+    /// composed protocols may use for other purposes.
+    #[serde(with = "crate::ark_serde::field", default = "ark_ff::One::one")]
+    pub coset_offset: F,
+
     /// The matrix commitment configuration.
     pub matrix_commit: matrix_commit::Config<F>,
 
@@ -148,21 +163,48 @@ where
         &self.embedding
     }
 
-    pub fn generator(&self) -> F {
-        ntt::generator(self.num_rows()).expect("Subgroup of requested size not found")
-    }
-
     pub fn rate(&self) -> f64 {
         1.0 / self.expansion as f64
     }
 
+    /// Whether a Reed-Solomon encoder is available for the codewords, see [`ntt::supports`].
+    pub fn has_encoder(&self) -> bool {
+        ntt::supports::<F>(self.num_rows())
+    }
+
+    /// Precompute the encoder tables for the codewords in `context`.
+    pub fn precompute_ntt(&self, context: &NttContext) {
+        ntt::precompute::<F>(context, self.num_rows());
+    }
+
     /// Commit to one or more vectors.
-    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(self = %self)))]
     pub fn commit<H, R>(
         &self,
         prover_state: &mut ProverState<H, R>,
         vectors: &[&[F]],
     ) -> Witness<F, G>
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        G: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        self.commit_with_buffer(NttContext::global(), prover_state, vectors, Vec::new())
+    }
+
+    /// Commit to one or more vectors, encoding into the allocation of `buffer` with the tables
+    /// in `context`.
+    ///
+    /// The contents of `buffer` are overwritten; passing the matrix of a witness that is no
+    /// longer needed avoids allocating a new one.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(self = %self)))]
+    pub fn commit_with_buffer<H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        vectors: &[&[F]],
+        mut buffer: Vec<F>,
+    ) -> Witness<F, G>
     where
         H: DuplexSpongeInterface,
         R: RngCore + CryptoRng,
@@ -180,12 +222,29 @@ where
             self.num_vectors * self.interleaving_depth
         );
 
+        assert_ne!(self.coset_offset, F::ZERO);
+        assert!(
+            self.basis == Basis::Coefficients
+                || (self.vector_size.is_power_of_two()
+                    && self.interleaving_depth.is_power_of_two())
+        );
+
         // Validate input
         assert_eq!(vectors.len(), self.num_vectors);
         assert!(vectors.iter().all(|p| p.len() == self.vector_size));
 
         // Interleaved RS Encode the vectorss
-        let matrix = interleaved_rs_encode(vectors, self.expansion, self.interleaving_depth);
+        buffer.resize(self.size(), F::ZERO);
+        interleaved_rs_encode_into(
+            context,
+            vectors,
+            self.expansion,
+            self.interleaving_depth,
+            self.basis,
+            self.coset_offset,
+            &mut buffer,
+        );
+        let matrix = buffer;
 
         // Commit to the matrix
         let matrix_witness = self.matrix_commit.commit(prover_state, &matrix);
@@ -194,8 +253,10 @@ where
         let oods_points: Vec<G> = prover_state.verifier_message_vec(self.out_domain_samples);
         let mut oods_matrix = Vec::with_capacity(self.out_domain_samples * self.num_vectors);
         for &point in &oods_points {
+            let evaluator =
+                UnivariateEvaluation::new(point, self.vector_size).with_basis(self.basis);
             for &vector in vectors {
-                let value = mixed_univariate_evaluate(&*self.embedding, vector, point);
+                let value = evaluator.evaluate(&*self.embedding, vector);
                 prover_state.prover_message(&value);
                 oods_matrix.push(value);
             }
@@ -358,10 +419,10 @@ where
         );
 
         // Compute corresponding in-domain evaluation points
-        let generator = self.generator();
+        let encoder = ntt::encoder::<F>();
         let points = indices
             .iter()
-            .map(|index| generator.pow([*index as u64]))
+            .map(|&index| self.coset_offset * encoder.evaluation_point(self.num_rows(), index))
             .collect::<Vec<_>>();
 
         (indices, points)
@@ -417,10 +478,15 @@ impl<F: Field> Evaluations<F> {
         }
     }
 
-    pub fn evaluators(&self, size: usize) -> impl '_ + Iterator<Item = UnivariateEvaluation<F>> {
+    /// Linear forms evaluating vectors of length `size` given in `basis` at the points.
+    pub fn evaluators(
+        &self,
+        size: usize,
+        basis: Basis,
+    ) -> impl '_ + Iterator<Item = UnivariateEvaluation<F>> {
         self.points
             .iter()
-            .map(move |&point| UnivariateEvaluation::new(point, size))
+            .map(move |&point| UnivariateEvaluation::new(point, size).with_basis(basis))
     }
 
     pub fn values<'a>(&'a self, weights: &'a [F]) -> impl 'a + Iterator<Item = F> {
@@ -464,7 +530,7 @@ mod tests {
     use crate::{
         algebra::{
             embedding::{Compose, Frobenius},
-            fields, univariate_evaluate,
+            fields, mixed_univariate_evaluate, univariate_evaluate,
         },
         transcript::{codecs::U64, DomainSeparator},
     };
@@ -500,24 +566,45 @@ mod tests {
             )
         });
 
-        (expansion_matrix, 0_usize..=10, 0_usize..=10, bool::ANY).prop_map(
-            move |(
-                (expansion, matrix_commit),
-                in_domain_samples,
-                out_domain_samples,
-                deduplicate_in_domain,
-            )| Config {
-                embedding: Typed::new(embedding.clone()),
-                num_vectors,
-                vector_size,
-                expansion,
-                interleaving_depth,
-                matrix_commit,
-                in_domain_samples,
-                out_domain_samples,
-                deduplicate_in_domain,
-            },
+        // The evaluation basis requires power-of-two vectors and blocks.
+        let basis = if vector_size.is_power_of_two() && interleaving_depth.is_power_of_two() {
+            select(vec![Basis::Coefficients, Basis::Evaluations]).boxed()
+        } else {
+            Just(Basis::Coefficients).boxed()
+        };
+
+        let coset_offset = select(vec![M::Source::ONE, M::Source::GENERATOR]);
+
+        (
+            expansion_matrix,
+            basis,
+            coset_offset,
+            0_usize..=10,
+            0_usize..=10,
+            bool::ANY,
         )
+            .prop_map(
+                move |(
+                    (expansion, matrix_commit),
+                    basis,
+                    coset_offset,
+                    in_domain_samples,
+                    out_domain_samples,
+                    deduplicate_in_domain,
+                )| Config {
+                    embedding: Typed::new(embedding.clone()),
+                    num_vectors,
+                    vector_size,
+                    expansion,
+                    interleaving_depth,
+                    basis,
+                    coset_offset,
+                    matrix_commit,
+                    in_domain_samples,
+                    out_domain_samples,
+                    deduplicate_in_domain,
+                },
+            )
     }
 
     fn test<M: Embedding>(seed: u64, config: &Config<M::Source, M::Target, M>)
@@ -567,8 +654,12 @@ mod tests {
                     .chunks_exact(config.num_vectors),
             ) {
                 for (vector, expected) in zip_strict(vectors.iter(), evals.iter()) {
+                    let mut coeffs = vector.clone();
+                    if config.basis == Basis::Evaluations {
+                        ntt::inverse_wavelet_transform(&mut coeffs);
+                    }
                     assert_eq!(
-                        mixed_univariate_evaluate(config.embedding(), vector, *point),
+                        mixed_univariate_evaluate(config.embedding(), &coeffs, *point),
                         *expected
                     );
                 }
@@ -603,7 +694,11 @@ mod tests {
                     (0..config.interleaving_depth).map(|j| {
                         // coefficients in the contiguous block for this interleaving index
                         let start = j * base;
-                        let coeffs: Vec<_> = poly.iter().copied().skip(start).take(base).collect();
+                        let mut coeffs: Vec<_> =
+                            poly.iter().copied().skip(start).take(base).collect();
+                        if config.basis == Basis::Evaluations {
+                            ntt::inverse_wavelet_transform(&mut coeffs);
+                        }
                         univariate_evaluate(&coeffs, *point)
                     })
                 });
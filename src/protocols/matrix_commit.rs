@@ -3,6 +3,7 @@
 use std::fmt;
 
 use ark_ff::{Field, PrimeField};
+use ark_serialize::CanonicalSerialize;
 use ark_std::rand::{CryptoRng, RngCore};
 use derive_where::derive_where;
 use serde::{Deserialize, Serialize};
@@ -60,7 +61,9 @@ assert_obj_safe!(Encoder<()>);
 ///
 /// It encodes reduced values in little-endian byte order using the minimum number
 /// of bytes that fit all field elements. For extensions,  the coefficients are
-/// encoded in order of increasing degree.
+/// encoded in order of increasing degree. Extensions of `𝔽_2` use their canonical
+/// serialization, which for binary tower fields are the little-endian bytes of
+/// [`BinaryTowerField::to_u128`](crate::algebra::fields::BinaryTowerField::to_u128).
 pub struct ArkFieldEncoder(Vec<u8>);
 
 /// Encoder for types that implement [`zerocopy`]'s [`Immutable`] and [`IntoBytes`].
@@ -118,6 +121,9 @@ pub type Commitment = merkle_tree::Commitment;
 /// Encode [`ark_ff::Field`]s using [`ArkFieldEncoder`].
 impl<F: Field> Encodable for F {
     fn encoded_size() -> usize {
+        if is_binary_extension::<F>() {
+            return F::ONE.uncompressed_size();
+        }
         let base_bytes = (F::BasePrimeField::MODULUS_BIT_SIZE as usize).div_ceil(8);
         base_bytes * F::extension_degree() as usize
     }
@@ -136,8 +142,16 @@ impl<F: Field> Encoder<F> for ArkFieldEncoder {
     where
         'd: 'e,
     {
-        let base_bytes = (F::BasePrimeField::MODULUS_BIT_SIZE as usize).div_ceil(8);
         self.0.clear();
+        if is_binary_extension::<F>() {
+            for value in values {
+                value
+                    .serialize_uncompressed(&mut self.0)
+                    .expect("Serialization to a vector is infallible");
+            }
+            return self.0.as_ref();
+        }
+        let base_bytes = (F::BasePrimeField::MODULUS_BIT_SIZE as usize).div_ceil(8);
         for value in values {
             for coeff in value.to_base_prime_field_elements() {
                 // Convert to regular reduced form (e.g. not Montgomery encoded).
@@ -155,6 +169,11 @@ impl<F: Field> Encoder<F> for ArkFieldEncoder {
     }
 }
 
+/// Whether `F` is an extension of `𝔽_2`, whose coefficients are single bits.
+fn is_binary_extension<F: Field>() -> bool {
+    F::characteristic() == [2] && F::extension_degree() > 1
+}
+
 impl<T: Immutable + IntoBytes> Encoder<T> for ZeroCopyEncoder {
     fn is_buffered(&self) -> bool {
         false
@@ -377,7 +396,7 @@ pub(crate) mod tests {
 
     use super::*;
     use crate::{
-        algebra::fields,
+        algebra::fields::{self, BinaryTowerField},
         hash::{self, tests::hash_for_size},
         transcript::{codecs::Empty, DomainSeparator},
     };
@@ -514,4 +533,26 @@ pub(crate) mod tests {
     fn test_field256() {
         proptest::<fields::Field256>();
     }
+
+    #[test]
+    fn test_binary_field32() {
+        proptest::<fields::BinaryField32>();
+    }
+
+    /// Binary fields encode as the little-endian bytes of their bits.
+    #[test]
+    fn test_binary_field_encoding() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let values: Vec<fields::BinaryField32> = (0..10).map(|_| rng.gen()).collect();
+        let expected = values
+            .iter()
+            .flat_map(|value| (value.to_u128() as u32).to_le_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(fields::BinaryField32::encoded_size(), 4);
+        assert_eq!(fields::BinaryField32::encoder().encode(&values), expected);
+
+        let bits = [fields::BinaryField1::from(0_u64), fields::BinaryField1::ONE];
+        assert_eq!(fields::BinaryField1::encoded_size(), 1);
+        assert_eq!(fields::BinaryField1::encoder().encode(&bits), [0, 1]);
+    }
 }
@@ -16,3 +16,4 @@ pub mod merkle_tree;
 pub mod proof_of_work;
 pub mod sumcheck;
 pub mod whir;
+pub mod zerocheck;
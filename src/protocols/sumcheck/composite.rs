@@ -0,0 +1,468 @@
+//! Sumcheck protocol for composite polynomials of arbitrary degree.
+
+use std::fmt;
+
+use ark_ff::Field;
+use ark_std::rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    algebra::{
+        embedding::{Embedding, Identity},
+        lift,
+        sumcheck::{
+            compute_composite_polynomial, fold, mixed_compute_product_composite_polynomial,
+            mixed_fold, ProductCovector,
+        },
+        univariate_evaluate, univariate_interpolate, MultilinearPoint,
+    },
+    ensure,
+    protocols::proof_of_work,
+    transcript::{
+        codecs::U64, Codec, Decoding, DuplexSpongeInterface, ProverState, VerificationResult,
+        VerifierMessage, VerifierState,
+    },
+    type_info::Type,
+    verify,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Config<F>
+where
+    F: Field,
+{
+    pub field: Type<F>,
+    pub initial_size: usize,
+    /// Upper bound on the total degree of the composition function.
+    pub degree: usize,
+    pub round_pow: proof_of_work::Config,
+    pub num_rounds: usize,
+}
+
+impl<F: Field> Config<F> {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        ensure!(
+            self.initial_size.is_power_of_two(),
+            "Initial size must be power of two."
+        );
+        ensure!(
+            self.initial_size.ilog2() as usize >= self.num_rounds,
+            "Initial size must be >= 2^{rounds}."
+        );
+        ensure!(self.degree >= 1, "Degree must be at least one.");
+        Ok(())
+    }
+
+    pub const fn final_size(&self) -> usize {
+        self.initial_size >> self.num_rounds
+    }
+
+    /// Runs the composite sumcheck protocol as configured.
+    ///
+    /// It reduces a claim of the form `∑_i f(a_1[i], …, a_k[i]) == sum` to an exponentially
+    /// smaller claim of the same form, where the `a_j` are folded in place and `f` is
+    /// `composition`. The round polynomials are sent without their linear coefficient,
+    /// which the verifier recovers from the sum.
+    ///
+    /// Returns the sampled folding randomness values used in each reduction step.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove<H, R, C>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        inputs: &mut [Vec<F>],
+        composition: &C,
+        sum: &mut F,
+    ) -> MultilinearPoint<F>
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        C: Fn(&[F]) -> F + Sync,
+    {
+        self.validate().expect("Invalid configuration");
+        assert!(inputs.iter().all(|input| input.len() == self.initial_size));
+        debug_assert_eq!(
+            (0..self.initial_size)
+                .map(|i| composition(&inputs.iter().map(|input| input[i]).collect::<Vec<_>>()))
+                .sum::<F>(),
+            *sum
+        );
+
+        let mut res = Vec::with_capacity(self.num_rounds);
+        for _ in 0..self.num_rounds {
+            let evaluations = compute_composite_polynomial(inputs, self.degree, composition);
+            let folding_randomness = self.round(prover_state, evaluations, sum);
+            res.push(folding_randomness);
+
+            // Fold the inputs
+            for input in inputs.iter_mut() {
+                fold(input, folding_randomness);
+            }
+        }
+
+        MultilinearPoint(res)
+    }
+
+    /// Runs [`Self::prove`] for `∑_i w_i · f(a_1[i], …, a_k[i])` with the weight `w` in
+    /// factored form and the `a_j` in the source field of `embedding`.
+    ///
+    /// This produces the same transcript as [`Self::prove`] with `w` materialized as an
+    /// additional input, but only stores tables of size about $√n$ of it. The composition
+    /// `f` must have total degree at most [`Self::degree`] minus one. The first round uses
+    /// mixed products, so the inputs are only lifted to the target field once they are
+    /// folded to half their size.
+    ///
+    /// Returns the folding randomness and the folded inputs.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove_product_mixed<H, R, M, C>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        embedding: &M,
+        mut weight: ProductCovector<F>,
+        inputs: &[&[M::Source]],
+        composition: &C,
+        sum: &mut F,
+    ) -> (MultilinearPoint<F>, Vec<Vec<F>>)
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        M: Embedding<Target = F>,
+        C: Fn(&[F]) -> F + Sync,
+    {
+        self.validate().expect("Invalid configuration");
+        assert_eq!(1 << weight.num_variables(), self.initial_size);
+        assert!(inputs.iter().all(|input| input.len() == self.initial_size));
+        debug_assert_eq!(
+            {
+                let mut weights = vec![F::ZERO; self.initial_size];
+                weight.accumulate(&mut weights);
+                weights
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| {
+                        let values = inputs
+                            .iter()
+                            .map(|input| embedding.map(input[i]))
+                            .collect::<Vec<_>>();
+                        *w * composition(&values)
+                    })
+                    .sum::<F>()
+            },
+            *sum
+        );
+        if self.num_rounds == 0 {
+            let inputs = inputs.iter().map(|input| lift(embedding, input)).collect();
+            return (MultilinearPoint(Vec::new()), inputs);
+        }
+
+        // The first round folds the inputs into the target field
+        let evaluations = mixed_compute_product_composite_polynomial(
+            embedding,
+            &weight,
+            inputs,
+            self.degree,
+            composition,
+        );
+        let folding_randomness = self.round(prover_state, evaluations, sum);
+        let mut inputs = inputs
+            .iter()
+            .map(|input| mixed_fold(embedding, input, folding_randomness))
+            .collect::<Vec<_>>();
+        weight.fold(folding_randomness);
+
+        let mut res = Vec::with_capacity(self.num_rounds);
+        res.push(folding_randomness);
+        let identity = Identity::<F>::new();
+        for _ in 1..self.num_rounds {
+            let slices = inputs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            let evaluations = mixed_compute_product_composite_polynomial(
+                &identity,
+                &weight,
+                &slices,
+                self.degree,
+                composition,
+            );
+            let folding_randomness = self.round(prover_state, evaluations, sum);
+            res.push(folding_randomness);
+
+            // Fold the inputs
+            for input in &mut inputs {
+                fold(input, folding_randomness);
+            }
+            weight.fold(folding_randomness);
+        }
+
+        (MultilinearPoint(res), inputs)
+    }
+
+    /// Sends the round polynomial given by its `evaluations` at `0, 2, 3, …, degree` and
+    /// returns the folding randomness, updating `sum` to the polynomial evaluated there.
+    fn round<H, R>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        mut evaluations: Vec<F>,
+        sum: &mut F,
+    ) -> F
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        // Send the sumcheck polynomial, except for the linear coefficient
+        evaluations.insert(1, *sum - evaluations[0]);
+        let coefficients = univariate_interpolate(&evaluations);
+        prover_state.prover_message(&coefficients[0]);
+        for coefficient in &coefficients[2..] {
+            prover_state.prover_message(coefficient);
+        }
+
+        // Do Proof of Work (if any)
+        self.round_pow.prove(prover_state);
+
+        // Receive the random evaluation point
+        let folding_randomness = prover_state.verifier_message::<F>();
+        *sum = univariate_evaluate(&coefficients, folding_randomness);
+        folding_randomness
+    }
+
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn verify<H>(
+        &self,
+        verifier_state: &mut VerifierState<H>,
+        sum: &mut F,
+    ) -> VerificationResult<MultilinearPoint<F>>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        verify!(self.validate().is_ok());
+
+        let mut res = Vec::with_capacity(self.num_rounds);
+        let mut coefficients = vec![F::ZERO; self.degree + 1];
+        for _ in 0..self.num_rounds {
+            // Receive the sumcheck polynomial and recover the linear coefficient
+            coefficients[0] = verifier_state.prover_message()?;
+            for coefficient in &mut coefficients[2..] {
+                *coefficient = verifier_state.prover_message()?;
+            }
+            coefficients[1] = *sum - coefficients[0].double() - coefficients[2..].iter().sum::<F>();
+
+            // Check proof of work (if any)
+            self.round_pow.verify(verifier_state)?;
+
+            // Receive the random evaluation point
+            let folding_randomness = verifier_state.verifier_message::<F>();
+            res.push(folding_randomness);
+
+            // Update the sum
+            *sum = univariate_evaluate(&coefficients, folding_randomness);
+        }
+
+        Ok(MultilinearPoint(res))
+    }
+}
+
+impl<F: Field> fmt::Display for Config<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "size {} degree {} rounds {} pow {:.2}",
+            self.initial_size,
+            self.degree,
+            self.num_rounds,
+            self.round_pow.difficulty()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::AdditiveGroup;
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::{
+        algebra::{
+            dot,
+            embedding::Basefield,
+            fields::{Field64, Field64_2},
+            ntt::NttContext,
+        },
+        bits::Bits,
+        protocols::sumcheck,
+        transcript::{codecs::Empty, DomainSeparator},
+    };
+
+    type F = Field64;
+
+    fn config(degree: usize, num_rounds: usize) -> Config<F> {
+        Config {
+            field: Type::new(),
+            initial_size: 16,
+            degree,
+            round_pow: proof_of_work::Config::from_difficulty(Bits::new(0.0)),
+            num_rounds,
+        }
+    }
+
+    #[test]
+    fn test_product() {
+        let mut rng = ark_std::test_rng();
+        let composition = |values: &[F]| values.iter().product::<F>();
+        for (degree, num_rounds) in [(1, 4), (3, 3), (4, 4)] {
+            let config = config(degree, num_rounds);
+            let mut inputs = (0..degree)
+                .map(|_| (0..16).map(|_| F::rand(&mut rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let initial_sum = (0..16)
+                .map(|i| composition(&inputs.iter().map(|input| input[i]).collect::<Vec<_>>()))
+                .sum::<F>();
+
+            let ds = DomainSeparator::protocol(&config)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+            let mut prover_state = ProverState::new_std(&ds);
+            let mut prover_sum = initial_sum;
+            let point = config.prove(
+                &mut prover_state,
+                &mut inputs,
+                &composition,
+                &mut prover_sum,
+            );
+            assert!(inputs
+                .iter()
+                .all(|input| input.len() == config.final_size()));
+            let final_sum = (0..config.final_size())
+                .map(|i| composition(&inputs.iter().map(|input| input[i]).collect::<Vec<_>>()))
+                .sum::<F>();
+            assert_eq!(prover_sum, final_sum);
+            let proof = prover_state.proof();
+
+            let mut verifier_state = VerifierState::new_std(&ds, &proof);
+            let mut verifier_sum = initial_sum;
+            let verifier_point = config
+                .verify(&mut verifier_state, &mut verifier_sum)
+                .unwrap();
+            assert_eq!(verifier_point, point);
+            assert_eq!(verifier_sum, prover_sum);
+            verifier_state.check_eof().unwrap();
+        }
+    }
+
+    /// The degree two case produces the same transcript as the quadratic sumcheck.
+    #[test]
+    fn test_matches_quadratic() {
+        let mut rng = ark_std::test_rng();
+        let config = config(2, 3);
+        let quadratic = sumcheck::Config {
+            field: Type::new(),
+            initial_size: config.initial_size,
+            round_pow: config.round_pow,
+            num_rounds: config.num_rounds,
+            univariate_skip: 0,
+            skip_pow: None,
+        };
+        let a = (0..16).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+        let b = (0..16).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+        let sum = dot(&a, &b);
+
+        let ds = DomainSeparator::protocol(&config)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let mut inputs = vec![a.clone(), b.clone()];
+        let composition = |values: &[F]| values[0] * values[1];
+        let mut composite_sum = sum;
+        config.prove(
+            &mut prover_state,
+            &mut inputs,
+            &composition,
+            &mut composite_sum,
+        );
+        let composite_proof = prover_state.proof();
+
+        let mut prover_state = ProverState::new_std(&ds);
+        let (mut a, mut b, mut quadratic_sum) = (a, b, sum);
+        quadratic.prove(
+            NttContext::global(),
+            &mut prover_state,
+            &mut a,
+            &mut b,
+            &mut quadratic_sum,
+        );
+        assert_eq!(quadratic_sum, composite_sum);
+        assert_eq!(prover_state.proof(), composite_proof);
+    }
+
+    /// A factored weight and base field inputs produce the same transcript as materializing
+    /// the weight and lifting the inputs.
+    #[test]
+    fn test_prove_product_mixed() {
+        type EF = Field64_2;
+        let mut rng = ark_std::test_rng();
+        let embedding = Basefield::<EF>::new();
+        let constraint = |values: &[EF]| values[0] * values[1] - values[2];
+        for num_rounds in [0, 1, 4] {
+            let config = Config::<EF> {
+                field: Type::new(),
+                initial_size: 16,
+                degree: 3,
+                round_pow: proof_of_work::Config::from_difficulty(Bits::new(0.0)),
+                num_rounds,
+            };
+            let point = (0..4).map(|_| EF::rand(&mut rng)).collect::<Vec<_>>();
+            let weight = ProductCovector::eq(&point, EF::rand(&mut rng));
+            let inputs = (0..3)
+                .map(|_| (0..16).map(|_| F::rand(&mut rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let mut lifted = vec![vec![EF::ZERO; 16]];
+            weight.accumulate(&mut lifted[0]);
+            lifted.extend(inputs.iter().map(|input| lift(&embedding, input)));
+            let composition = |values: &[EF]| values[0] * constraint(&values[1..]);
+            let sum = (0..16)
+                .map(|i| composition(&lifted.iter().map(|input| input[i]).collect::<Vec<_>>()))
+                .sum::<EF>();
+
+            let ds = DomainSeparator::protocol(&config)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+            let mut prover_state = ProverState::new_std(&ds);
+            let mut lifted_sum = sum;
+            let lifted_point = config.prove(
+                &mut prover_state,
+                &mut lifted,
+                &composition,
+                &mut lifted_sum,
+            );
+            let lifted_proof = prover_state.proof();
+
+            let mut prover_state = ProverState::new_std(&ds);
+            let mut product_sum = sum;
+            let inputs = inputs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            let (product_point, folded) = config.prove_product_mixed(
+                &mut prover_state,
+                &embedding,
+                weight,
+                &inputs,
+                &constraint,
+                &mut product_sum,
+            );
+            assert_eq!(prover_state.proof(), lifted_proof);
+            assert_eq!(product_point, lifted_point);
+            assert_eq!(folded, lifted[1..]);
+            assert_eq!(product_sum, lifted_sum);
+        }
+    }
+}
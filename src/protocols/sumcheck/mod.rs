@@ -0,0 +1,827 @@
+//! Quadratic sumcheck protocol.
+//!
+//! The first rounds can optionally be collapsed into a single univariate round over a
+//! multiplicative subgroup, see [`Config::univariate_skip`]. See [`composite`] for
+//! composite polynomials of higher degree.
+
+pub mod composite;
+
+use std::fmt;
+
+use ark_ff::{FftField, Field};
+use ark_std::rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    algebra::{
+        dot,
+        embedding::{Embedding, Identity},
+        lift,
+        linear_form::LinearForm,
+        mixed_dot,
+        ntt::{self, NttContext},
+        sumcheck::{
+            compute_sumcheck_polynomial, fold, fold_weighted, lagrange_weights,
+            mixed_compute_product_sumcheck_polynomial, mixed_compute_skip_polynomial,
+            mixed_compute_sumcheck_polynomial, mixed_fold, mixed_fold_weighted,
+            univariate_skip_evaluate, ProductCovector,
+        },
+        tensor_product, univariate_evaluate, MultilinearPoint,
+    },
+    ensure,
+    protocols::proof_of_work,
+    transcript::{
+        codecs::U64, Codec, Decoding, DuplexSpongeInterface, ProverState, VerificationResult,
+        VerifierMessage, VerifierState,
+    },
+    type_info::Type,
+    verify,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Config<F>
+where
+    F: Field,
+{
+    pub field: Type<F>,
+    pub initial_size: usize,
+    pub round_pow: proof_of_work::Config,
+    pub num_rounds: usize,
+    /// Number of initial rounds collapsed into a single univariate round, or zero.
+    ///
+    /// The univariate round sends a polynomial of degree `2^(skip + 1) - 2` over the
+    /// subgroup of order `2^skip` and folds with its Lagrange basis instead of the
+    /// equality polynomial. The field must have a subgroup of order `2^(skip + 1)`.
+    pub univariate_skip: usize,
+    /// Proof of work of the univariate skip round, or [`Self::round_pow`] if `None`.
+    ///
+    /// The skip round has a larger soundness error than the other rounds, so it may need more
+    /// grinding.
+    pub skip_pow: Option<proof_of_work::Config>,
+}
+
+impl<F: Field> Config<F> {
+    pub const fn final_size(&self) -> usize {
+        self.initial_size >> self.num_rounds
+    }
+
+    /// Number of verifier challenges, which is the size of the returned folding point.
+    pub const fn num_challenges(&self) -> usize {
+        if self.univariate_skip == 0 {
+            self.num_rounds
+        } else {
+            self.num_rounds - self.univariate_skip + 1
+        }
+    }
+}
+
+impl<F: FftField> Config<F> {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        ensure!(
+            self.initial_size.is_power_of_two(),
+            "Initial size must be power of two."
+        );
+        ensure!(
+            self.initial_size.ilog2() as usize >= self.num_rounds,
+            "Initial size must be >= 2^{rounds}."
+        );
+        ensure!(
+            self.univariate_skip <= self.num_rounds,
+            "Univariate skip must be <= rounds."
+        );
+        ensure!(
+            self.univariate_skip == 0
+                || 1_usize
+                    .checked_shl(self.univariate_skip as u32 + 1)
+                    .and_then(ntt::generator::<F>)
+                    .is_some(),
+            "Univariate skip requires a subgroup of order 2^{skip + 1}."
+        );
+        Ok(())
+    }
+
+    /// The weights with which [`Self::prove`] folds the blocks of its inputs at `point`.
+    ///
+    /// This is the tensor product of the Lagrange basis in the univariate skip coordinate,
+    /// if any, and the equality polynomial in the remaining coordinates.
+    pub fn folding_weights(&self, point: &MultilinearPoint<F>) -> Vec<F> {
+        assert_eq!(point.num_variables(), self.num_challenges());
+        if self.univariate_skip == 0 {
+            return point.eq_weights();
+        }
+        let (&skip_point, rest) = point
+            .0
+            .split_first()
+            .expect("Missing univariate skip point");
+        tensor_product(
+            &lagrange_weights(1 << self.univariate_skip, skip_point),
+            &MultilinearPoint(rest.to_vec()).eq_weights(),
+        )
+    }
+
+    /// The multilinear extension of `linear_form` folded at `point`.
+    ///
+    /// The `point` starts with the folding randomness of this sumcheck and may continue with
+    /// that of later reductions. Without univariate skip this is [`LinearForm::mle_evaluate`].
+    pub fn mle_evaluate(&self, linear_form: &(impl LinearForm<F> + ?Sized), point: &[F]) -> F {
+        univariate_skip_evaluate(linear_form, self.univariate_skip, point)
+    }
+
+    /// Runs the quadratic sumcheck protocol as configured.
+    ///
+    /// It reduces a claim of the form `dot(a, b) == sum` to an exponentially
+    /// smaller claim `dot(a', b') == sum'` where `a'` is `a` folded in place
+    /// and similarly for `b`.
+    ///
+    /// This function:
+    /// - Samples random values to progressively reduce the polynomial.
+    /// - Applies proof-of-work grinding if required.
+    /// - Returns the sampled folding randomness values used in each reduction step.
+    ///
+    /// With [`Self::univariate_skip`] the first value folds several variables at once, see
+    /// [`Self::folding_weights`], and the skip polynomial is interpolated with the tables in
+    /// `context`.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove<H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        a: &mut Vec<F>,
+        b: &mut Vec<F>,
+        sum: &mut F,
+    ) -> MultilinearPoint<F>
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        self.validate().expect("Invalid configuration");
+        assert_eq!(a.len(), self.initial_size);
+        assert_eq!(b.len(), self.initial_size);
+        debug_assert_eq!(dot(a, b), *sum);
+        if self.univariate_skip > 0 {
+            let (folding_randomness, folded) =
+                self.prove_skip(context, prover_state, &Identity::<F>::new(), a, b, sum);
+            *a = folded;
+            return folding_randomness;
+        }
+
+        let mut res = Vec::with_capacity(self.num_rounds);
+        for _ in 0..self.num_rounds {
+            let (c0, c2) = compute_sumcheck_polynomial(a, b);
+            let folding_randomness = self.round(prover_state, c0, c2, sum);
+            res.push(folding_randomness);
+
+            // Fold the inputs
+            fold(a, folding_randomness);
+            fold(b, folding_randomness);
+        }
+
+        MultilinearPoint(res)
+    }
+
+    /// Runs [`Self::prove`] with `a` in the source field of `embedding`.
+    ///
+    /// The first round uses mixed products, so `a` is only lifted to the target field once
+    /// it is folded to half its size. Returns the folding randomness and the folded `a`.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove_mixed<H, R, M>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        embedding: &M,
+        a: &[M::Source],
+        b: &mut Vec<F>,
+        sum: &mut F,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        M: Embedding<Target = F>,
+    {
+        assert_eq!(b.len(), self.initial_size);
+        debug_assert_eq!(mixed_dot(embedding, b, a), *sum);
+        if self.univariate_skip > 0 {
+            return self.prove_skip(context, prover_state, embedding, a, b, sum);
+        }
+        self.prove_mixed_inner(prover_state, embedding, a, b, sum)
+    }
+
+    /// Runs the quadratic sumcheck protocol with `b` a sum of factored covectors.
+    ///
+    /// This produces the same transcript as [`Self::prove`] on the materialized `b`, but
+    /// only stores tables of size about $√n$ of it. This is the case for `b` built from
+    /// point evaluations (eq polynomials) and univariate evaluations. The factored form does
+    /// not survive folding with [`Self::univariate_skip`], in which case `b` is materialized.
+    ///
+    /// Returns the folding randomness and `b` folded to [`Self::final_size`].
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove_product<H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        a: &mut Vec<F>,
+        covectors: Vec<ProductCovector<F>>,
+        sum: &mut F,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        let (folding_randomness, folded, b) = self.prove_product_mixed(
+            context,
+            prover_state,
+            &Identity::<F>::new(),
+            a,
+            covectors,
+            sum,
+        );
+        *a = folded;
+        (folding_randomness, b)
+    }
+
+    /// Runs [`Self::prove_product`] with `a` in the source field of `embedding`.
+    ///
+    /// See [`Self::prove_mixed`]. Returns the folding randomness and the folded `a` and `b`.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove_product_mixed<H, R, M>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        embedding: &M,
+        a: &[M::Source],
+        mut covectors: Vec<ProductCovector<F>>,
+        sum: &mut F,
+    ) -> (MultilinearPoint<F>, Vec<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        M: Embedding<Target = F>,
+    {
+        for covector in &covectors {
+            assert_eq!(1 << covector.num_variables(), self.initial_size);
+        }
+        debug_assert_eq!(
+            covectors
+                .iter()
+                .map(|covector| covector.mixed_dot(embedding, a))
+                .sum::<F>(),
+            *sum
+        );
+        if self.univariate_skip > 0 {
+            let mut b = vec![F::ZERO; self.initial_size];
+            for covector in &covectors {
+                covector.accumulate(&mut b);
+            }
+            let (folding_randomness, a) =
+                self.prove_skip(context, prover_state, embedding, a, &mut b, sum);
+            return (folding_randomness, a, b);
+        }
+
+        let (folding_randomness, a) =
+            self.prove_mixed_inner(prover_state, embedding, a, &mut covectors, sum);
+        let mut b = vec![F::ZERO; self.final_size()];
+        for covector in &covectors {
+            covector.accumulate(&mut b);
+        }
+        (folding_randomness, a, b)
+    }
+
+    fn prove_mixed_inner<H, R, M, B>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        embedding: &M,
+        a: &[M::Source],
+        b: &mut B,
+        sum: &mut F,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        M: Embedding<Target = F>,
+        B: SumcheckCovector<F>,
+    {
+        self.validate().expect("Invalid configuration");
+        assert_eq!(a.len(), self.initial_size);
+        if self.num_rounds == 0 {
+            return (MultilinearPoint(Vec::new()), lift(embedding, a));
+        }
+
+        // The first round folds `a` into the target field
+        let (c0, c2) = b.sumcheck_polynomial(embedding, a, *sum);
+        let folding_randomness = self.round(prover_state, c0, c2, sum);
+        let mut a = mixed_fold(embedding, a, folding_randomness);
+        b.fold(folding_randomness);
+
+        let mut res = Vec::with_capacity(self.num_rounds);
+        res.push(folding_randomness);
+        let identity = Identity::<F>::new();
+        for _ in 1..self.num_rounds {
+            let (c0, c2) = b.sumcheck_polynomial(&identity, &a, *sum);
+            let folding_randomness = self.round(prover_state, c0, c2, sum);
+            res.push(folding_randomness);
+
+            // Fold the inputs
+            fold(&mut a, folding_randomness);
+            b.fold(folding_randomness);
+        }
+
+        (MultilinearPoint(res), a)
+    }
+
+    /// Runs the univariate skip round followed by the remaining quadratic rounds.
+    fn prove_skip<H, R, M>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        embedding: &M,
+        a: &[M::Source],
+        b: &mut Vec<F>,
+        sum: &mut F,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        M: Embedding<Target = F>,
+    {
+        self.validate().expect("Invalid configuration");
+        assert_eq!(a.len(), self.initial_size);
+        let domain_size = 1 << self.univariate_skip;
+
+        // Send the skip polynomial, except for the coefficient of degree `domain_size`
+        let coefficients = mixed_compute_skip_polynomial(context, embedding, domain_size, a, b);
+        for (degree, coefficient) in coefficients.iter().enumerate() {
+            if degree != domain_size {
+                prover_state.prover_message(coefficient);
+            }
+        }
+
+        // Do Proof of Work (if any)
+        self.skip_pow.unwrap_or(self.round_pow).prove(prover_state);
+
+        // Receive the random evaluation point and fold the blocks
+        let folding_randomness = prover_state.verifier_message::<F>();
+        *sum = univariate_evaluate(&coefficients, folding_randomness);
+        let weights = lagrange_weights(domain_size, folding_randomness);
+        let mut a = mixed_fold_weighted(embedding, a, &weights);
+        fold_weighted(b, &weights);
+
+        let mut res = Vec::with_capacity(self.num_challenges());
+        res.push(folding_randomness);
+        for _ in self.univariate_skip..self.num_rounds {
+            let (c0, c2) = compute_sumcheck_polynomial(&a, b);
+            let folding_randomness = self.round(prover_state, c0, c2, sum);
+            res.push(folding_randomness);
+
+            // Fold the inputs
+            fold(&mut a, folding_randomness);
+            fold(b, folding_randomness);
+        }
+
+        (MultilinearPoint(res), a)
+    }
+
+    /// Sends the sumcheck polynomial `c0`, `c2` and returns the folding randomness.
+    fn round<H, R>(&self, prover_state: &mut ProverState<H, R>, c0: F, c2: F, sum: &mut F) -> F
+    where
+        H: DuplexSpongeInterface,
+        R: CryptoRng + RngCore,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        // Send sumcheck polynomial c0 and c2
+        let c1 = *sum - c0.double() - c2;
+        prover_state.prover_message(&c0);
+        prover_state.prover_message(&c2);
+
+        // Do Proof of Work (if any)
+        self.round_pow.prove(prover_state);
+
+        // Receive the random evaluation point
+        let folding_randomness = prover_state.verifier_message::<F>();
+        *sum = (c2 * folding_randomness + c1) * folding_randomness + c0;
+        folding_randomness
+    }
+
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn verify<H>(
+        &self,
+        verifier_state: &mut VerifierState<H>,
+        sum: &mut F,
+    ) -> VerificationResult<MultilinearPoint<F>>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        verify!(self.validate().is_ok());
+
+        let mut res = Vec::with_capacity(self.num_challenges());
+        if self.univariate_skip > 0 {
+            // Receive the skip polynomial and recover the coefficient of degree `domain_size`,
+            // as the sum over the subgroup is `domain_size · (c_0 + c_domain_size)`.
+            let domain_size = 1 << self.univariate_skip;
+            let mut coefficients = vec![F::ZERO; 2 * domain_size - 1];
+            for (degree, coefficient) in coefficients.iter_mut().enumerate() {
+                if degree != domain_size {
+                    *coefficient = verifier_state.prover_message()?;
+                }
+            }
+            let inverse = F::from(domain_size as u64).inverse();
+            verify!(inverse.is_some());
+            coefficients[domain_size] = *sum * inverse.unwrap() - coefficients[0];
+
+            // Check proof of work (if any)
+            self.skip_pow
+                .unwrap_or(self.round_pow)
+                .verify(verifier_state)?;
+
+            // Receive the random evaluation point
+            let folding_randomness = verifier_state.verifier_message::<F>();
+            res.push(folding_randomness);
+
+            // Update the sum
+            *sum = univariate_evaluate(&coefficients, folding_randomness);
+        }
+        for _ in self.univariate_skip..self.num_rounds {
+            // Receive sumcheck polynomial c0 and c2
+            let c0: F = verifier_state.prover_message()?;
+            let c2: F = verifier_state.prover_message()?;
+            let c1 = *sum - c0.double() - c2;
+
+            // Check proof of work (if any)
+            self.round_pow.verify(verifier_state)?;
+
+            // Receive the random evaluation point
+            let folding_randomness = verifier_state.verifier_message::<F>();
+            res.push(folding_randomness);
+
+            // Update the sum
+            *sum = (c2 * folding_randomness + c1) * folding_randomness + c0;
+        }
+
+        Ok(MultilinearPoint(res))
+    }
+}
+
+/// The `b` side of the quadratic sumcheck, materialized or factored.
+trait SumcheckCovector<F: Field> {
+    fn sumcheck_polynomial<M: Embedding<Target = F>>(
+        &self,
+        embedding: &M,
+        a: &[M::Source],
+        sum: F,
+    ) -> (F, F);
+
+    fn fold(&mut self, weight: F);
+}
+
+impl<F: Field> SumcheckCovector<F> for Vec<F> {
+    fn sumcheck_polynomial<M: Embedding<Target = F>>(
+        &self,
+        embedding: &M,
+        a: &[M::Source],
+        _sum: F,
+    ) -> (F, F) {
+        mixed_compute_sumcheck_polynomial(embedding, a, self)
+    }
+
+    fn fold(&mut self, weight: F) {
+        fold(self, weight);
+    }
+}
+
+impl<F: Field> SumcheckCovector<F> for Vec<ProductCovector<F>> {
+    fn sumcheck_polynomial<M: Embedding<Target = F>>(
+        &self,
+        embedding: &M,
+        a: &[M::Source],
+        sum: F,
+    ) -> (F, F) {
+        mixed_compute_product_sumcheck_polynomial(embedding, a, self, sum)
+    }
+
+    fn fold(&mut self, weight: F) {
+        for covector in self {
+            covector.fold(weight);
+        }
+    }
+}
+
+impl<F: Field> fmt::Display for Config<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "size {} rounds {} pow {:.2}",
+            self.initial_size,
+            self.num_rounds,
+            self.round_pow.difficulty()
+        )?;
+        if self.univariate_skip > 0 {
+            write!(
+                f,
+                " skip {} pow {:.2}",
+                self.univariate_skip,
+                self.skip_pow.unwrap_or(self.round_pow).difficulty()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::AdditiveGroup;
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::{
+        algebra::{
+            embedding::Basefield,
+            fields::{BinaryField128, Field64, Field64_2, Mersenne31},
+            linear_form::MultilinearExtension,
+        },
+        bits::Bits,
+        transcript::{codecs::Empty, DomainSeparator},
+    };
+
+    type F = Field64;
+
+    // TODO: Proptest based tests checking invariants and post conditions.
+
+    /// The factored prover produces the same transcript as the materialized one.
+    #[test]
+    fn test_prove_product() {
+        let mut rng = ark_std::test_rng();
+        let num_variables = 5;
+        let config = Config::<F> {
+            field: Type::new(),
+            initial_size: 1 << num_variables,
+            round_pow: proof_of_work::Config::from_difficulty(Bits::new(0.0)),
+            num_rounds: 3,
+            univariate_skip: 0,
+            skip_pow: None,
+        };
+        let point = (0..num_variables)
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        for covectors in [
+            vec![ProductCovector::eq(&point, F::ONE)],
+            vec![
+                ProductCovector::eq(&point, F::rand(&mut rng)),
+                ProductCovector::univariate(F::rand(&mut rng), num_variables, F::rand(&mut rng)),
+            ],
+            vec![ProductCovector::eq(&[F::ZERO; 5], F::rand(&mut rng))],
+        ] {
+            let a = (0..config.initial_size)
+                .map(|_| F::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let mut b = vec![F::ZERO; config.initial_size];
+            for covector in &covectors {
+                covector.accumulate(&mut b);
+            }
+            let sum = dot(&a, &b);
+            let ds = DomainSeparator::protocol(&config)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+
+            let mut prover_state = ProverState::new_std(&ds);
+            let (mut dense_a, mut dense_sum) = (a.clone(), sum);
+            let dense_point = config.prove(
+                NttContext::global(),
+                &mut prover_state,
+                &mut dense_a,
+                &mut b,
+                &mut dense_sum,
+            );
+            let dense_proof = prover_state.proof();
+
+            let mut prover_state = ProverState::new_std(&ds);
+            let (mut product_a, mut product_sum) = (a, sum);
+            let (product_point, product_b) = config.prove_product(
+                NttContext::global(),
+                &mut prover_state,
+                &mut product_a,
+                covectors,
+                &mut product_sum,
+            );
+            assert_eq!(prover_state.proof(), dense_proof);
+            assert_eq!(product_point, dense_point);
+            assert_eq!(product_a, dense_a);
+            assert_eq!(product_b, b);
+            assert_eq!(product_sum, dense_sum);
+        }
+    }
+
+    /// Keeping `a` in the base field produces the same transcript as lifting it.
+    #[test]
+    fn test_prove_mixed() {
+        type EF = Field64_2;
+        let mut rng = ark_std::test_rng();
+        let num_variables = 4;
+        let config = Config::<EF> {
+            field: Type::new(),
+            initial_size: 1 << num_variables,
+            round_pow: proof_of_work::Config::from_difficulty(Bits::new(0.0)),
+            num_rounds: 2,
+            univariate_skip: 0,
+            skip_pow: None,
+        };
+        let embedding = Basefield::<EF>::new();
+        let a = (0..config.initial_size)
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let point = (0..num_variables)
+            .map(|_| EF::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let covectors = vec![ProductCovector::eq(&point, EF::rand(&mut rng))];
+        let mut b = vec![EF::ZERO; config.initial_size];
+        covectors[0].accumulate(&mut b);
+        let sum = mixed_dot(&embedding, &b, &a);
+        let ds = DomainSeparator::protocol(&config)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+
+        let mut prover_state = ProverState::new_std(&ds);
+        let (mut lifted_a, mut lifted_b, mut lifted_sum) = (lift(&embedding, &a), b.clone(), sum);
+        let lifted_point = config.prove(
+            NttContext::global(),
+            &mut prover_state,
+            &mut lifted_a,
+            &mut lifted_b,
+            &mut lifted_sum,
+        );
+        let lifted_proof = prover_state.proof();
+
+        let mut prover_state = ProverState::new_std(&ds);
+        let (mut mixed_b, mut mixed_sum) = (b, sum);
+        let (mixed_point, mixed_a) = config.prove_mixed(
+            NttContext::global(),
+            &mut prover_state,
+            &embedding,
+            &a,
+            &mut mixed_b,
+            &mut mixed_sum,
+        );
+        assert_eq!(prover_state.proof(), lifted_proof);
+        assert_eq!(mixed_point, lifted_point);
+        assert_eq!(mixed_a, lifted_a);
+        assert_eq!(mixed_b, lifted_b);
+        assert_eq!(mixed_sum, lifted_sum);
+
+        let mut prover_state = ProverState::new_std(&ds);
+        let mut product_sum = sum;
+        let (product_point, product_a, product_b) = config.prove_product_mixed(
+            NttContext::global(),
+            &mut prover_state,
+            &embedding,
+            &a,
+            covectors,
+            &mut product_sum,
+        );
+        assert_eq!(prover_state.proof(), lifted_proof);
+        assert_eq!(product_point, lifted_point);
+        assert_eq!(product_a, lifted_a);
+        assert_eq!(product_b, lifted_b);
+        assert_eq!(product_sum, lifted_sum);
+    }
+
+    /// The univariate skip round is accepted and folds consistently with the linear forms.
+    #[test]
+    fn test_univariate_skip() {
+        let mut rng = ark_std::test_rng();
+        let num_variables = 5;
+        for (num_rounds, univariate_skip) in [(5, 1), (5, 3), (4, 2), (2, 2)] {
+            let config = Config::<F> {
+                field: Type::new(),
+                initial_size: 1 << num_variables,
+                round_pow: proof_of_work::Config::from_difficulty(Bits::new(0.0)),
+                num_rounds,
+                univariate_skip,
+                skip_pow: None,
+            };
+            let a = (0..config.initial_size)
+                .map(|_| F::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let point = (0..num_variables)
+                .map(|_| F::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let linear_form = MultilinearExtension::new(point.clone());
+            let mut b = vec![F::ZERO; config.initial_size];
+            linear_form.accumulate(&mut b, F::ONE);
+            let sum = dot(&a, &b);
+            let ds = DomainSeparator::protocol(&config)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+
+            let mut prover_state = ProverState::new_std(&ds);
+            let (mut folded_a, mut folded_b, mut prover_sum) = (a.clone(), b.clone(), sum);
+            let folding_randomness = config.prove(
+                NttContext::global(),
+                &mut prover_state,
+                &mut folded_a,
+                &mut folded_b,
+                &mut prover_sum,
+            );
+            assert_eq!(folding_randomness.num_variables(), config.num_challenges());
+            assert_eq!(dot(&folded_a, &folded_b), prover_sum);
+
+            // The folded vectors are the weighted sums of the blocks.
+            let weights = config.folding_weights(&folding_randomness);
+            let mut expected_a = a.clone();
+            fold_weighted(&mut expected_a, &weights);
+            assert_eq!(folded_a, expected_a);
+            if num_rounds == num_variables {
+                assert_eq!(
+                    folded_b,
+                    vec![config.mle_evaluate(&linear_form, &folding_randomness.0)]
+                );
+            }
+
+            let proof = prover_state.proof();
+
+            // Factored covectors are materialized for the skip round.
+            let mut product_state = ProverState::new_std(&ds);
+            let (mut product_a, mut product_sum) = (a.clone(), sum);
+            let (product_randomness, product_b) = config.prove_product(
+                NttContext::global(),
+                &mut product_state,
+                &mut product_a,
+                vec![ProductCovector::eq(&point, F::ONE)],
+                &mut product_sum,
+            );
+            assert_eq!(product_randomness, folding_randomness);
+            assert_eq!(product_b, folded_b);
+            assert_eq!(product_state.proof(), proof);
+
+            // The skip polynomial is interpolated with the tables of the given context.
+            let context = NttContext::new(usize::MAX);
+            let mut context_state = ProverState::new_std(&ds);
+            let (mut context_a, mut context_b, mut context_sum) = (a, b, sum);
+            let context_randomness = config.prove(
+                &context,
+                &mut context_state,
+                &mut context_a,
+                &mut context_b,
+                &mut context_sum,
+            );
+            assert_eq!(context_randomness, folding_randomness);
+            assert_eq!(context_state.proof(), proof);
+            assert_eq!(
+                context.memory_usage(),
+                (2 << univariate_skip) * size_of::<F>()
+            );
+
+            let mut verifier_state = VerifierState::new_std(&ds, &proof);
+            let mut verifier_sum = sum;
+            let verifier_randomness = config
+                .verify(&mut verifier_state, &mut verifier_sum)
+                .unwrap();
+            assert_eq!(verifier_randomness, folding_randomness);
+            assert_eq!(verifier_sum, prover_sum);
+            verifier_state.check_eof().unwrap();
+        }
+    }
+
+    /// The univariate skip needs a subgroup of order `2^(skip + 1)`.
+    #[test]
+    fn test_validate_univariate_skip() {
+        fn config<F: FftField>(univariate_skip: usize) -> Config<F> {
+            Config {
+                field: Type::new(),
+                initial_size: 1 << 5,
+                round_pow: proof_of_work::Config::from_difficulty(Bits::new(0.0)),
+                num_rounds: 5,
+                univariate_skip,
+                skip_pow: None,
+            }
+        }
+        assert!(config::<F>(5).validate().is_ok());
+        assert!(config::<BinaryField128>(0).validate().is_ok());
+        assert!(config::<BinaryField128>(1).validate().is_err());
+        assert!(config::<Mersenne31>(0).validate().is_ok());
+        assert!(config::<Mersenne31>(1).validate().is_err());
+    }
+}
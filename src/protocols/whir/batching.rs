@@ -184,6 +184,7 @@ mod batching_tests {
                 &weights_dyn_refs,
                 &values
             )
+            .and_then(|claims| claims.check_with(&weights_dyn_refs))
             .is_ok());
     }
 
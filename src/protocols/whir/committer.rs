@@ -7,7 +7,7 @@ use tracing::instrument;
 
 use super::Config;
 use crate::{
-    algebra::embedding::Embedding,
+    algebra::{embedding::Embedding, ntt::NttContext, MultilinearPolynomial},
     hash::Hash,
     protocols::irs_commit,
     transcript::{
@@ -25,7 +25,6 @@ where
     M::Source: FftField,
 {
     /// Commit to one or more vectors.
-    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(size = vectors.first().unwrap().len())))]
     pub fn commit<H, R>(
         &self,
         prover_state: &mut ProverState<H, R>,
@@ -37,7 +36,50 @@ where
         F: Codec<[H::U]>,
         Hash: ProverMessage<[H::U]>,
     {
-        self.initial_committer.commit(prover_state, vectors)
+        self.commit_with_context(NttContext::global(), prover_state, vectors)
+    }
+
+    /// Commit to one or more vectors using the encoder tables in `context`.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(size = vectors.first().unwrap().len())))]
+    pub fn commit_with_context<H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        vectors: &[&[M::Source]],
+    ) -> Witness<F, M>
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        self.initial_committer
+            .commit_with_buffer(context, prover_state, vectors, Vec::new())
+    }
+
+    /// Commit to multilinear polynomials, given in any basis, using the encoder tables in
+    /// `context`.
+    ///
+    /// The committed vectors are their values in the [`irs_commit::Config::basis`] of the
+    /// initial commitment, see [`Self::prove_polynomials`].
+    pub fn commit_polynomials<H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        polynomials: &[&MultilinearPolynomial<M::Source>],
+    ) -> Witness<F, M>
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        let vectors = polynomials
+            .iter()
+            .map(|polynomial| polynomial.values_in(self.initial_committer.basis))
+            .collect::<Vec<_>>();
+        let vectors = vectors.iter().map(|vector| &vector[..]).collect::<Vec<_>>();
+        self.commit_with_context(context, prover_state, &vectors)
     }
 
     /// Receive a commitment to vectors.
@@ -1,5 +1,6 @@
 use core::panic;
 use std::{
+    any::type_name,
     f64::consts::LOG2_10,
     fmt::{Debug, Display},
     ops::Neg,
@@ -12,6 +13,8 @@ use crate::{
     algebra::{
         embedding::{self, Basefield, Embedding},
         fields::FieldWithSize,
+        ntt::NttContext,
+        Basis,
     },
     bits::Bits,
     parameters::{MultivariateParameters, ProtocolParameters, SoundnessType},
@@ -32,6 +35,15 @@ where
     pub round_configs: Vec<RoundConfig<F>>,
     pub final_sumcheck: sumcheck::Config<F>,
     pub final_pow: proof_of_work::Config,
+    /// Proof of work before the random combination of the evaluation points, which has
+    /// error `1 / |F|`.
+    pub evaluations_pow: proof_of_work::Config,
+    /// Sumcheck reducing evaluations at several points to a single point, see
+    /// [`Self::prove`].
+    pub evaluations_sumcheck: sumcheck::Config<F>,
+    /// Whether the configuration is sound for proving linear forms, see
+    /// [`ProtocolParameters::initial_statement`].
+    pub initial_statement: bool,
 
     // TODO: These don't belong in the config. Instead there should be
     // fn like `WhirConfig::soundness(&self, assumptions: SoundnessType) -> Bits`.
@@ -54,10 +66,51 @@ impl<F> Config<F, Basefield<F>>
 where
     F: FftField + FieldWithSize,
 {
-    #[allow(clippy::too_many_lines)]
+    /// Configuration committing to vectors over the base prime field of `F`, see
+    /// [`Self::with_embedding`].
     pub fn new(
         mv_parameters: MultivariateParameters<F>,
         whir_parameters: &ProtocolParameters,
+    ) -> Self {
+        Self::with_embedding(Basefield::new(), mv_parameters, whir_parameters)
+    }
+
+    /// Configuration for using WHIR as a low-degree test.
+    ///
+    /// Same as [`Self::new`] with `initial_statement` disabled, which omits the out-of-domain
+    /// samples on the initial commitment. The resulting configuration is only sound for
+    /// [`Self::prove_proximity`] and [`Self::verify_proximity`], not for proving linear forms.
+    pub fn new_proximity(
+        mv_parameters: MultivariateParameters<F>,
+        whir_parameters: &ProtocolParameters,
+    ) -> Self {
+        Self::new(
+            mv_parameters,
+            &ProtocolParameters {
+                initial_statement: false,
+                ..whir_parameters.clone()
+            },
+        )
+    }
+}
+
+impl<F, M> Config<F, M>
+where
+    F: FftField + FieldWithSize,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// Configuration committing to vectors over `M::Source`, with the challenges and all later
+    /// rounds in `F`.
+    ///
+    /// The soundness is computed from the size of `F`, so e.g. vectors over `𝔽_{p^2}` can be
+    /// committed with challenges in `𝔽_{p^6}` through [`embedding::CubicExtension`], and
+    /// embeddings can be chained with [`embedding::Compose`].
+    #[allow(clippy::too_many_lines)]
+    pub fn with_embedding(
+        embedding: M,
+        mv_parameters: MultivariateParameters<F>,
+        whir_parameters: &ProtocolParameters,
     ) -> Self {
         whir_parameters
             .folding_factor
@@ -186,6 +239,8 @@ where
                     vector_size: 1 << num_variables,
                     expansion: 1 << next_rate,
                     interleaving_depth: 1 << next_folding_factor,
+                    basis: Basis::Coefficients,
+                    coset_offset: F::ONE,
                     matrix_commit: matrix_committer.clone(),
                     in_domain_samples: Self::queries(
                         whir_parameters.soundness_type,
@@ -200,6 +255,8 @@ where
                     initial_size: 1 << num_variables,
                     round_pow: pow(folding_pow_bits),
                     num_rounds: next_folding_factor,
+                    univariate_skip: 0,
+                    skip_pow: None,
                 },
                 pow: pow(pow_bits),
             });
@@ -227,15 +284,20 @@ where
         let final_folding_pow_bits =
             0_f64.max(whir_parameters.security_level as f64 - (field_size_bits - 1) as f64);
 
-        Self {
+        let evaluations_pow_bits =
+            0_f64.max(whir_parameters.security_level as f64 - field_size_bits as f64);
+
+        let config = Self {
             security_level: whir_parameters.security_level,
             soundness_type: whir_parameters.soundness_type,
             initial_committer: irs_commit::Config {
-                embedding: Default::default(),
+                embedding: Typed::new(embedding),
                 num_vectors: whir_parameters.batch_size,
                 vector_size: 1 << mv_parameters.num_variables,
                 expansion: 1 << whir_parameters.starting_log_inv_rate,
                 interleaving_depth: 1 << whir_parameters.folding_factor.at_round(0),
+                basis: Basis::Coefficients,
+                coset_offset: M::Source::ONE,
                 matrix_commit: matrix_commit::Config::with_hash(
                     whir_parameters.hash_id,
                     1 << (mv_parameters.num_variables + whir_parameters.starting_log_inv_rate
@@ -255,6 +317,8 @@ where
                 initial_size: 1 << mv_parameters.num_variables,
                 round_pow: pow(starting_folding_pow_bits),
                 num_rounds: whir_parameters.folding_factor.at_round(0),
+                univariate_skip: 0,
+                skip_pow: None,
             },
             round_configs: round_parameters,
             final_sumcheck: sumcheck::Config {
@@ -262,8 +326,79 @@ where
                 initial_size: 1 << num_variables,
                 round_pow: pow(final_folding_pow_bits),
                 num_rounds: final_sumcheck_rounds,
+                univariate_skip: 0,
+                skip_pow: None,
             },
             final_pow: pow(final_pow_bits),
+            evaluations_pow: pow(evaluations_pow_bits),
+            evaluations_sumcheck: sumcheck::Config {
+                field: Type::<F>::new(),
+                initial_size: 1 << mv_parameters.num_variables,
+                round_pow: pow(final_folding_pow_bits),
+                num_rounds: mv_parameters.num_variables,
+                univariate_skip: 0,
+                skip_pow: None,
+            },
+            initial_statement: whir_parameters.initial_statement,
+        };
+        config.assert_encoders();
+        config
+    }
+
+    /// Collapses the first `univariate_skip` rounds of the initial sumcheck into a single
+    /// univariate round, see [`sumcheck::Config::univariate_skip`].
+    ///
+    /// The skip round gets its own proof of work to cover its larger soundness error, the
+    /// remaining rounds keep the initial folding proof of work.
+    pub fn with_univariate_skip(mut self, univariate_skip: usize) -> Self {
+        self.initial_sumcheck.univariate_skip = univariate_skip;
+        self.initial_sumcheck
+            .validate()
+            .expect("Invalid univariate skip");
+        if univariate_skip > 0 {
+            let log_inv_rate = self.initial_committer.rate().log2().neg();
+            let (prox_gaps_error, sumcheck_error) = Self::rbr_soundness_univariate_skip(
+                self.soundness_type,
+                F::field_size_in_bits(),
+                self.initial_num_variables(),
+                log_inv_rate,
+                Self::log_eta(self.soundness_type, log_inv_rate),
+                univariate_skip,
+            );
+            let difficulty = (self.security_level as f64 - prox_gaps_error.min(sumcheck_error))
+                .max(f64::from(self.initial_sumcheck.round_pow.difficulty()));
+            self.initial_sumcheck.skip_pow = Some(proof_of_work::Config {
+                hash_id: self.initial_sumcheck.round_pow.hash_id,
+                threshold: proof_of_work::threshold(Bits::new(difficulty)),
+            });
+        } else {
+            self.initial_sumcheck.skip_pow = None;
+        }
+        self
+    }
+
+    /// Commit to vectors given in `basis`, see [`irs_commit::Config::basis`].
+    ///
+    /// With [`Basis::Evaluations`] the committed vectors are hypercube evaluations, so
+    /// [`MultilinearExtension`](crate::algebra::linear_form::MultilinearExtension) claims
+    /// are the evaluations of the committed multilinear polynomial.
+    pub const fn with_basis(mut self, basis: Basis) -> Self {
+        self.initial_committer.basis = basis;
+        self
+    }
+
+    /// Relative Hamming distance `δ` up to which the low-degree test is sound.
+    ///
+    /// If [`Self::verify_proximity`] accepts then, except with probability `2^-security_level`,
+    /// the committed words are `δ`-close to the initial Reed-Solomon code. Depending on the
+    /// soundness type this is `(1 - ρ) / 2`, `1 - √ρ - η` or `1 - ρ - η` for initial rate `ρ`.
+    pub fn proximity(&self) -> f64 {
+        let rate = self.initial_committer.rate();
+        let eta = Self::log_eta(self.soundness_type, -rate.log2()).exp2();
+        match self.soundness_type {
+            SoundnessType::UniqueDecoding => (1. - rate) / 2.,
+            SoundnessType::ProvableList => 1. - rate.sqrt() - eta,
+            SoundnessType::ConjectureList => 1. - rate - eta,
         }
     }
 
@@ -271,6 +406,11 @@ where
         if self.initial_sumcheck.round_pow.difficulty() > max_bits {
             return false;
         }
+        if let Some(skip_pow) = self.initial_sumcheck.skip_pow {
+            if skip_pow.difficulty() > max_bits {
+                return false;
+            }
+        }
         for round_config in &self.round_configs {
             if round_config.pow.difficulty() > max_bits {
                 return false;
@@ -285,6 +425,12 @@ where
         if self.final_sumcheck.round_pow.difficulty() > max_bits {
             return false;
         }
+        if self.evaluations_pow.difficulty() > max_bits {
+            return false;
+        }
+        if self.evaluations_sumcheck.round_pow.difficulty() > max_bits {
+            return false;
+        }
         true
     }
 
@@ -383,6 +529,31 @@ where
         field_size_bits as f64 - (list_size + 1.)
     }
 
+    /// Round-by-round errors `(prox gaps, sumcheck)` of a univariate skip round.
+    ///
+    /// The skip polynomial has degree `2^(skip + 1) - 2` instead of two, and the folded word
+    /// is a curve of degree `2^skip - 1` in the blocks instead of a line.
+    pub fn rbr_soundness_univariate_skip(
+        soundness_type: SoundnessType,
+        field_size_bits: usize,
+        num_variables: usize,
+        log_inv_rate: f64,
+        log_eta: f64,
+        univariate_skip: usize,
+    ) -> (f64, f64) {
+        let domain_size = (1_usize << univariate_skip) as f64;
+        let prox_gaps_error = Self::rbr_soundness_fold_prox_gaps(
+            soundness_type,
+            field_size_bits,
+            num_variables,
+            log_inv_rate,
+            log_eta,
+        ) - (domain_size - 1.).log2();
+        let list_size = Self::list_size_bits(soundness_type, num_variables, log_inv_rate, log_eta);
+        let sumcheck_error = field_size_bits as f64 - (list_size + (2. * domain_size - 2.).log2());
+        (prox_gaps_error, sumcheck_error)
+    }
+
     pub const fn folding_pow_bits(
         security_level: usize,
         soundness_type: SoundnessType,
@@ -518,6 +689,49 @@ where
         )
     }
 
+    /// Evaluate the codewords of every commitment on the coset `offset · ⟨ω⟩`, see
+    /// [`irs_commit::Config::coset_offset`].
+    ///
+    /// The round commitments use the offset embedded in `F`.
+    pub fn with_coset_offset(mut self, offset: M::Source) -> Self {
+        assert_ne!(offset, M::Source::ZERO);
+        self.initial_committer.coset_offset = offset;
+        let round_offset = self.embedding().map(offset);
+        for round_config in &mut self.round_configs {
+            round_config.irs_committer.coset_offset = round_offset;
+        }
+        self
+    }
+
+    /// Precompute in `context` the encoder tables of the initial and all round commitments.
+    ///
+    /// Pass the context to [`Self::commit_with_context`] and [`Self::prove_with_context`].
+    pub fn precompute_ntt(&self, context: &NttContext) {
+        self.initial_committer.precompute_ntt(context);
+        for round_config in &self.round_configs {
+            round_config.irs_committer.precompute_ntt(context);
+        }
+    }
+
+    /// Panics unless Reed-Solomon encoders exist for the committed vectors over `M::Source`
+    /// and the folded vectors over `F`.
+    pub(crate) fn assert_encoders(&self) {
+        assert!(
+            self.initial_committer.has_encoder(),
+            "No Reed-Solomon encoder for {} of size {}",
+            type_name::<M::Source>(),
+            self.initial_committer.num_rows()
+        );
+        for round_config in &self.round_configs {
+            assert!(
+                round_config.irs_committer.has_encoder(),
+                "No Reed-Solomon encoder for {} of size {}",
+                type_name::<F>(),
+                round_config.irs_committer.num_rows()
+            );
+        }
+    }
+
     pub fn final_in_domain_samples(&self) -> usize {
         self.round_configs
             .last()
@@ -527,7 +741,12 @@ where
     }
 }
 
-impl<F: FftField> Display for Config<F> {
+impl<F, M> Display for Config<F, M>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
     #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(
@@ -573,30 +792,76 @@ impl<F: FftField> Display for Config<F> {
             )?;
         }
 
-        let prox_gaps_error = Self::rbr_soundness_fold_prox_gaps(
-            self.soundness_type,
-            field_size_bits,
-            num_variables,
-            self.initial_committer.rate().log2().neg(),
-            log_eta,
-        );
-        let sumcheck_error = Self::rbr_soundness_fold_sumcheck(
-            self.soundness_type,
-            field_size_bits,
-            num_variables,
-            self.initial_committer.rate().log2().neg(),
-            log_eta,
-        );
-        writeln!(
-            f,
-            "{:.1} bits -- (x{}) prox gaps: {:.1}, sumcheck: {:.1}, pow: {:.1}",
-            prox_gaps_error.min(sumcheck_error)
-                + f64::from(self.initial_sumcheck.round_pow.difficulty()),
-            self.initial_sumcheck.num_rounds,
-            prox_gaps_error,
-            sumcheck_error,
-            self.initial_sumcheck.round_pow.difficulty(),
-        )?;
+        if self.initial_statement {
+            let combination_error = field_size_bits as f64;
+            let sumcheck_error = field_size_bits as f64 - 1.;
+            writeln!(
+                f,
+                "{:.1} bits -- evaluations combination: {:.1}, pow: {:.1}",
+                combination_error + f64::from(self.evaluations_pow.difficulty()),
+                combination_error,
+                self.evaluations_pow.difficulty(),
+            )?;
+            writeln!(
+                f,
+                "{:.1} bits -- (x{}) evaluations sumcheck: {:.1}, pow: {:.1}",
+                sumcheck_error + f64::from(self.evaluations_sumcheck.round_pow.difficulty()),
+                self.evaluations_sumcheck.num_rounds,
+                sumcheck_error,
+                self.evaluations_sumcheck.round_pow.difficulty(),
+            )?;
+        }
+
+        let univariate_skip = self.initial_sumcheck.univariate_skip;
+        if univariate_skip > 0 {
+            let (prox_gaps_error, sumcheck_error) = Self::rbr_soundness_univariate_skip(
+                self.soundness_type,
+                field_size_bits,
+                num_variables,
+                self.initial_committer.rate().log2().neg(),
+                log_eta,
+                univariate_skip,
+            );
+            let skip_pow = self
+                .initial_sumcheck
+                .skip_pow
+                .unwrap_or(self.initial_sumcheck.round_pow);
+            writeln!(
+                f,
+                "{:.1} bits -- (skip {}) prox gaps: {:.1}, sumcheck: {:.1}, pow: {:.1}",
+                prox_gaps_error.min(sumcheck_error) + f64::from(skip_pow.difficulty()),
+                univariate_skip,
+                prox_gaps_error,
+                sumcheck_error,
+                skip_pow.difficulty(),
+            )?;
+        }
+        if self.initial_sumcheck.num_rounds > univariate_skip {
+            let prox_gaps_error = Self::rbr_soundness_fold_prox_gaps(
+                self.soundness_type,
+                field_size_bits,
+                num_variables,
+                self.initial_committer.rate().log2().neg(),
+                log_eta,
+            );
+            let sumcheck_error = Self::rbr_soundness_fold_sumcheck(
+                self.soundness_type,
+                field_size_bits,
+                num_variables,
+                self.initial_committer.rate().log2().neg(),
+                log_eta,
+            );
+            writeln!(
+                f,
+                "{:.1} bits -- (x{}) prox gaps: {:.1}, sumcheck: {:.1}, pow: {:.1}",
+                prox_gaps_error.min(sumcheck_error)
+                    + f64::from(self.initial_sumcheck.round_pow.difficulty()),
+                self.initial_sumcheck.num_rounds - univariate_skip,
+                prox_gaps_error,
+                sumcheck_error,
+                self.initial_sumcheck.round_pow.difficulty(),
+            )?;
+        }
 
         num_variables -= self.initial_sumcheck.num_rounds;
 
@@ -737,9 +1002,15 @@ where
 
 #[cfg(test)]
 mod tests {
+    use ark_ff::Field;
+
     use super::*;
     use crate::{
-        algebra::fields::Field64, bits::Bits, hash, parameters::FoldingFactor, utils::test_serde,
+        algebra::fields::{BinaryField128, BinaryField8, Field64, Field64_3, Mersenne31},
+        bits::Bits,
+        hash,
+        parameters::FoldingFactor,
+        utils::test_serde,
     };
 
     /// Generates default WHIR parameters
@@ -756,6 +1027,34 @@ mod tests {
         }
     }
 
+    #[test]
+    #[should_panic(expected = "No Reed-Solomon encoder")]
+    fn test_whir_config_without_encoder() {
+        // Mersenne31 has no multiplicative subgroup of order four.
+        let mv_params = MultivariateParameters::<Mersenne31>::new(10);
+        let _ = Config::<Mersenne31>::new(mv_params, &default_whir_params());
+    }
+
+    #[test]
+    #[should_panic(expected = "No Reed-Solomon encoder")]
+    fn test_whir_config_binary_base_field() {
+        // The base prime field of the tower is 𝔽_2, which has no evaluation domain.
+        let mv_params = MultivariateParameters::<BinaryField128>::new(10);
+        let _ = Config::<BinaryField128>::new(mv_params, &default_whir_params());
+    }
+
+    #[test]
+    #[should_panic(expected = "No Reed-Solomon encoder")]
+    fn test_whir_config_subspace_too_large() {
+        // 512 rows do not fit in the subspaces of 𝔽_{2^8}.
+        let mv_params = MultivariateParameters::<BinaryField128>::new(12);
+        let _ = Config::with_embedding(
+            embedding::Subfield::<BinaryField8, BinaryField128>::new(),
+            mv_params,
+            &default_whir_params(),
+        );
+    }
+
     #[test]
     fn test_whir_params_serde() {
         test_serde(&default_whir_params());
@@ -772,6 +1071,32 @@ mod tests {
         test_serde(&config);
     }
 
+    #[test]
+    fn test_with_embedding() {
+        // The soundness only depends on the target field, not the committed one.
+        let params = default_whir_params();
+        let mv_params = MultivariateParameters::<Field64_3>::new(10);
+        let config = Config::<Field64_3>::new(mv_params, &params);
+        let embedded =
+            Config::with_embedding(embedding::Identity::<Field64_3>::new(), mv_params, &params);
+        test_serde(&embedded);
+
+        let (committer, embedded_committer) =
+            (&config.initial_committer, &embedded.initial_committer);
+        assert_eq!(embedded_committer.num_rows(), committer.num_rows());
+        assert_eq!(
+            embedded_committer.in_domain_samples,
+            committer.in_domain_samples
+        );
+        assert_eq!(
+            embedded_committer.out_domain_samples,
+            committer.out_domain_samples
+        );
+        assert_eq!(embedded.initial_sumcheck, config.initial_sumcheck);
+        assert_eq!(embedded.round_configs, config.round_configs);
+        assert_eq!(embedded.final_sumcheck, config.final_sumcheck);
+    }
+
     #[test]
     fn test_n_rounds() {
         let params = default_whir_params();
@@ -881,6 +1206,9 @@ mod tests {
         config.initial_sumcheck.round_pow = proof_of_work::Config::from_difficulty(Bits::new(15.0));
         config.final_pow = proof_of_work::Config::from_difficulty(Bits::new(18.0));
         config.final_sumcheck.round_pow = proof_of_work::Config::from_difficulty(Bits::new(19.5));
+        config.evaluations_pow = proof_of_work::Config::from_difficulty(Bits::new(19.0));
+        config.evaluations_sumcheck.round_pow =
+            proof_of_work::Config::from_difficulty(Bits::new(19.5));
 
         // Ensure all rounds are within limits
         config.round_configs = vec![
@@ -891,6 +1219,8 @@ mod tests {
                     vector_size: 1 << 10,
                     expansion: 1 << 3,
                     interleaving_depth: 1 << 2,
+                    basis: Basis::Coefficients,
+                    coset_offset: Field64::ONE,
                     matrix_commit: matrix_commit::Config::<Field64>::new(0, 0),
                     in_domain_samples: 5,
                     out_domain_samples: 2,
@@ -901,6 +1231,8 @@ mod tests {
                     initial_size: 1 << 10,
                     round_pow: proof_of_work::Config::from_difficulty(Bits::new(19.0)),
                     num_rounds: 2,
+                    univariate_skip: 0,
+                    skip_pow: None,
                 },
                 pow: proof_of_work::Config::from_difficulty(Bits::new(17.0)),
             },
@@ -911,6 +1243,8 @@ mod tests {
                     vector_size: 1 << 10,
                     expansion: 1 << 4,
                     interleaving_depth: 1 << 2,
+                    basis: Basis::Coefficients,
+                    coset_offset: Field64::ONE,
                     matrix_commit: matrix_commit::Config::<Field64>::new(0, 0),
                     in_domain_samples: 6,
                     out_domain_samples: 2,
@@ -921,6 +1255,8 @@ mod tests {
                     initial_size: 1 << 10,
                     round_pow: proof_of_work::Config::from_difficulty(Bits::new(19.5)),
                     num_rounds: 2,
+                    univariate_skip: 0,
+                    skip_pow: None,
                 },
                 pow: proof_of_work::Config::from_difficulty(Bits::new(18.0)),
             },
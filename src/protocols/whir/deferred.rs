@@ -0,0 +1,148 @@
+use ark_ff::FftField;
+
+use crate::{
+    algebra::{linear_form::LinearForm, sumcheck::univariate_skip_evaluate, MultilinearPoint},
+    transcript::VerificationResult,
+    verify,
+};
+
+/// Claimed evaluations of the deferred linear forms, as returned by [`super::Config::verify`].
+///
+/// For every linear form with [`LinearForm::deferred`] the verifier accepted the prover's
+/// value of [`LinearForm::mle_evaluate`] at [`Self::point`] without checking it. The proof
+/// is only sound once these claims are discharged, either directly with [`Self::check_with`]
+/// or by an outer protocol through [`Self::into_claims`].
+///
+/// With a univariate skip in the initial sumcheck, the first coordinate of the point
+/// replaces the first [`Self::univariate_skip`] variables, see
+/// [`crate::protocols::sumcheck::Config::mle_evaluate`].
+#[must_use = "deferred claims must be checked or handed off"]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeferredClaims<F: FftField> {
+    point: MultilinearPoint<F>,
+    univariate_skip: usize,
+    values: Vec<F>,
+}
+
+/// Deferred claims handed off to an outer protocol by [`DeferredClaims::into_claims`].
+///
+/// Each value is claimed to be [`univariate_skip_evaluate`] of the corresponding deferred
+/// linear form with `univariate_skip` at `point`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct OpenClaims<F: FftField> {
+    pub point: MultilinearPoint<F>,
+    pub univariate_skip: usize,
+    pub values: Vec<F>,
+}
+
+impl<F: FftField> DeferredClaims<F> {
+    pub(super) const fn new(
+        point: MultilinearPoint<F>,
+        univariate_skip: usize,
+        values: Vec<F>,
+    ) -> Self {
+        Self {
+            point,
+            univariate_skip,
+            values,
+        }
+    }
+
+    /// The point at which the deferred linear forms are evaluated.
+    pub const fn point(&self) -> &MultilinearPoint<F> {
+        &self.point
+    }
+
+    /// The number of variables folded by the first coordinate of [`Self::point`].
+    pub const fn univariate_skip(&self) -> usize {
+        self.univariate_skip
+    }
+
+    /// The claimed values, one for each deferred linear form in order.
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    /// Check the claims by evaluating the deferred forms among `linear_forms`.
+    ///
+    /// The `linear_forms` are the same as passed to [`super::Config::verify`]. Returns the
+    /// evaluation point on success.
+    pub fn check_with(
+        self,
+        linear_forms: &[&dyn LinearForm<F>],
+    ) -> VerificationResult<MultilinearPoint<F>> {
+        let mut values = self.values.into_iter();
+        for linear_form in linear_forms.iter().filter(|form| form.deferred()) {
+            let value = univariate_skip_evaluate(*linear_form, self.univariate_skip, &self.point.0);
+            verify!(values.next() == Some(value));
+        }
+        verify!(values.next().is_none());
+        Ok(self.point)
+    }
+
+    /// The evaluation point, univariate skip and claimed values, for an outer protocol to
+    /// discharge.
+    pub fn into_claims(self) -> OpenClaims<F> {
+        OpenClaims {
+            point: self.point,
+            univariate_skip: self.univariate_skip,
+            values: self.values,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::algebra::{
+        fields::Field64_2,
+        linear_form::{Covector, MultilinearExtension},
+    };
+
+    type F = Field64_2;
+
+    fn claims_and_forms() -> (DeferredClaims<F>, Covector<F>, MultilinearExtension<F>) {
+        let mut rng = ark_std::test_rng();
+        let point = MultilinearPoint((0..3).map(|_| F::rand(&mut rng)).collect());
+        let covector = Covector {
+            deferred: true,
+            vector: (0..8).map(|_| F::rand(&mut rng)).collect(),
+        };
+        let mle = MultilinearExtension::new((0..3).map(|_| F::rand(&mut rng)).collect());
+        let value = covector.mle_evaluate(&point.0);
+        (DeferredClaims::new(point, 0, vec![value]), covector, mle)
+    }
+
+    #[test]
+    fn test_check_with() {
+        let (claims, covector, mle) = claims_and_forms();
+        let point = claims.point().clone();
+        assert_eq!(claims.check_with(&[&mle, &covector]).unwrap(), point);
+    }
+
+    #[test]
+    fn test_into_claims() {
+        let (claims, covector, _) = claims_and_forms();
+        let claims = claims.into_claims();
+        assert_eq!(claims.univariate_skip, 0);
+        assert_eq!(claims.values, vec![covector.mle_evaluate(&claims.point.0)]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_check_with_wrong_value() {
+        let (claims, covector, _) = claims_and_forms();
+        let OpenClaims { point, values, .. } = claims.into_claims();
+        let claims = DeferredClaims::new(point, 0, vec![values[0] + F::from(1)]);
+        assert!(claims.check_with(&[&covector]).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_check_with_missing_form() {
+        let (claims, _, mle) = claims_and_forms();
+        assert!(claims.check_with(&[&mle]).is_err());
+    }
+}
@@ -0,0 +1,303 @@
+//! Evaluations of committed vectors at many points.
+//!
+//! Claims at `k` points add `k` [`MultilinearExtension`] forms to the initial sumcheck. Up to
+//! [`MAX_PRODUCT_COVECTORS`] constraints stay factored there, beyond that each of them is
+//! materialized with a pass over the vector. In that case [`Config::prove`] first runs
+//! [`Config::evaluations_sumcheck`] on the batched vector over the random linear combination
+//! of their factored equality polynomials, which reduces them to a claim at a single point.
+
+use std::any::Any;
+
+use ark_ff::FftField;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use super::{config::Config, prover::MAX_PRODUCT_COVECTORS};
+use crate::{
+    algebra::{
+        dot,
+        embedding::Embedding,
+        linear_form::{LinearForm, MultilinearExtension},
+        ntt::NttContext,
+        sumcheck::ProductCovector,
+        MultilinearPoint,
+    },
+    transcript::{
+        codecs::U64, Codec, Decoding, DuplexSpongeInterface, ProverState, VerificationResult,
+        VerifierMessage, VerifierState,
+    },
+    utils::zip_strict,
+    verify,
+};
+
+/// Smallest number of variables for which the evaluations are reduced to a single point.
+///
+/// The reduction costs a pass over the vector per point in each of its `n` rounds, where
+/// materializing the points costs a single pass each, and adds `2n + 1` field elements to the
+/// proof. With the vector in the base field of [`Field64_2`](crate::algebra::fields::Field64_2)
+/// it breaks even around size 2^10 and is about 1.5× faster from size 2^16 on, for five to
+/// thirty-two points (see `benches/evaluations.rs`).
+const MIN_EVALUATIONS_NUM_VARIABLES: usize = 16;
+
+impl<F, M> Config<F, M>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// The points of `linear_forms`, if [`Self::prove`] reduces them to a single point.
+    ///
+    /// This is the case for two or more [`MultilinearExtension`]s and nothing else that do not
+    /// stay factored in the initial sumcheck together with the `num_oods` out-of-domain
+    /// samples, on at least [`MIN_EVALUATIONS_NUM_VARIABLES`] variables.
+    pub(super) fn evaluation_points(
+        &self,
+        linear_forms: &[&dyn LinearForm<F>],
+        num_oods: usize,
+    ) -> Option<Vec<MultilinearPoint<F>>> {
+        if linear_forms.len() < 2
+            || linear_forms.len() + num_oods <= MAX_PRODUCT_COVECTORS
+            || self.initial_num_variables() < MIN_EVALUATIONS_NUM_VARIABLES
+        {
+            return None;
+        }
+        linear_forms
+            .iter()
+            .map(|&linear_form| {
+                (linear_form as &dyn Any)
+                    .downcast_ref::<MultilinearExtension<F>>()
+                    .map(|mle| MultilinearPoint(mle.point.clone()))
+            })
+            .collect()
+    }
+
+    /// Reduce the evaluations of the batched `vector` at `points` to an evaluation at a single
+    /// point.
+    ///
+    /// The `vector` is in the source field of `embedding`, with one of the `evaluations` per
+    /// point. Returns the linear form of the single point and the evaluation on it.
+    pub(super) fn prove_evaluations<H, R, E>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        embedding: &E,
+        vector: &[E::Source],
+        points: &[MultilinearPoint<F>],
+        evaluations: &[F],
+    ) -> (MultilinearExtension<F>, F)
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        E: Embedding<Target = F>,
+    {
+        assert_eq!(evaluations.len(), points.len());
+
+        // Random linear combination of the claims.
+        self.evaluations_pow.prove(prover_state);
+        let point_rlc_coeffs: Vec<F> = prover_state.verifier_message_vec(points.len());
+        let mut sum = dot(&point_rlc_coeffs, evaluations);
+        let covectors = zip_strict(points, &point_rlc_coeffs)
+            .map(|(point, coeff)| ProductCovector::eq(&point.0, *coeff))
+            .collect();
+
+        // Reduce to an evaluation at a single point.
+        let (folding_randomness, folded, _) = self.evaluations_sumcheck.prove_product_mixed(
+            context,
+            prover_state,
+            embedding,
+            vector,
+            covectors,
+            &mut sum,
+        );
+        let evaluation = folded[0];
+        prover_state.prover_message(&evaluation);
+        (MultilinearExtension::new(folding_randomness.0), evaluation)
+    }
+
+    /// Verify the reduction of [`Self::prove_evaluations`].
+    pub(super) fn verify_evaluations<H>(
+        &self,
+        verifier_state: &mut VerifierState<'_, H>,
+        points: &[MultilinearPoint<F>],
+        evaluations: &[F],
+    ) -> VerificationResult<(MultilinearExtension<F>, F)>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+    {
+        verify!(evaluations.len() == points.len());
+
+        // Random linear combination of the claims.
+        self.evaluations_pow.verify(verifier_state)?;
+        let point_rlc_coeffs: Vec<F> = verifier_state.verifier_message_vec(points.len());
+        let mut sum = dot(&point_rlc_coeffs, evaluations);
+
+        // Reduce to an evaluation at a single point.
+        let folding_randomness = self.evaluations_sumcheck.verify(verifier_state, &mut sum)?;
+        let evaluation: F = verifier_state.prover_message()?;
+        let weight = zip_strict(points, &point_rlc_coeffs)
+            .map(|(point, coeff)| {
+                *coeff
+                    * MultilinearExtension::new(point.0.clone()).mle_evaluate(&folding_randomness.0)
+            })
+            .sum::<F>();
+        verify!(sum == weight * evaluation);
+        Ok((MultilinearExtension::new(folding_randomness.0), evaluation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use ark_ff::UniformRand;
+
+    use super::{super::Config, MIN_EVALUATIONS_NUM_VARIABLES};
+    use crate::{
+        algebra::{
+            fields::{Field64, Field64_2},
+            linear_form::{Evaluate, LinearForm, MultilinearExtension},
+            MultilinearPoint,
+        },
+        hash,
+        parameters::{FoldingFactor, MultivariateParameters, ProtocolParameters, SoundnessType},
+        transcript::{codecs::Empty, DomainSeparator, ProverState, VerifierState},
+    };
+
+    /// Field type used in the tests.
+    type F = Field64;
+
+    /// Extension field type used in the tests.
+    type EF = Field64_2;
+
+    fn config(num_variables: usize, batch_size: usize) -> Config<EF> {
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size,
+            hash_id: hash::SHA2,
+        };
+        Config::new(MultivariateParameters::new(num_variables), &whir_params)
+    }
+
+    fn run_evaluations(num_variables: usize, batch_size: usize, num_points: usize, tamper: bool) {
+        let mut rng = ark_std::test_rng();
+        let params = config(num_variables, batch_size);
+
+        let vectors: Vec<Vec<F>> = (0..batch_size)
+            .map(|_| (0..1 << num_variables).map(|_| F::rand(&mut rng)).collect())
+            .collect();
+        let vec_refs = vectors.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let points = (0..num_points)
+            .map(|_| MultilinearPoint::rand(&mut rng, num_variables))
+            .collect::<Vec<_>>();
+        let mut evaluations = points
+            .iter()
+            .flat_map(|point| {
+                let linear_form = MultilinearExtension::new(point.0.clone());
+                vec_refs
+                    .iter()
+                    .map(|vector| linear_form.evaluate(params.embedding(), vector))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let linear_forms = points
+            .iter()
+            .map(|point| MultilinearExtension::new(point.0.clone()))
+            .collect::<Vec<_>>();
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &vec_refs);
+        params.prove(
+            &mut prover_state,
+            vec_refs.iter().map(|v| Cow::Borrowed(*v)).collect(),
+            vec![Cow::Owned(witness)],
+            points
+                .iter()
+                .map(|point| {
+                    Box::new(MultilinearExtension::new(point.0.clone())) as Box<dyn LinearForm<EF>>
+                })
+                .collect(),
+            Cow::Borrowed(evaluations.as_slice()),
+        );
+        if tamper {
+            evaluations[0] += EF::from(1);
+        }
+
+        let proof = prover_state.proof();
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        let linear_form_refs = linear_forms
+            .iter()
+            .map(|form| form as &dyn LinearForm<EF>)
+            .collect::<Vec<_>>();
+        let result = params
+            .verify(
+                &mut verifier_state,
+                &[&commitment],
+                &linear_form_refs,
+                &evaluations,
+            )
+            .and_then(|claims| claims.check_with(&linear_form_refs));
+        assert_eq!(result.is_ok(), !tamper);
+    }
+
+    /// Only claims at more points than stay factored in the initial sumcheck, on large
+    /// enough vectors, are reduced to a single point.
+    #[test]
+    fn test_evaluation_points() {
+        let mut rng = ark_std::test_rng();
+        for (num_variables, num_points, num_oods, reduced) in [
+            (MIN_EVALUATIONS_NUM_VARIABLES, 1, 4, false),
+            (MIN_EVALUATIONS_NUM_VARIABLES, 4, 0, false),
+            (MIN_EVALUATIONS_NUM_VARIABLES, 3, 2, true),
+            (MIN_EVALUATIONS_NUM_VARIABLES, 6, 0, true),
+            (MIN_EVALUATIONS_NUM_VARIABLES - 1, 6, 0, false),
+        ] {
+            let params = config(num_variables, 1);
+            let linear_forms = (0..num_points)
+                .map(|_| {
+                    MultilinearExtension::new(MultilinearPoint::rand(&mut rng, num_variables).0)
+                })
+                .collect::<Vec<_>>();
+            let linear_form_refs = linear_forms
+                .iter()
+                .map(|form| form as &dyn LinearForm<EF>)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                params
+                    .evaluation_points(&linear_form_refs, num_oods)
+                    .is_some(),
+                reduced
+            );
+        }
+    }
+
+    #[test]
+    fn test_evaluations() {
+        for num_variables in [2, 6, MIN_EVALUATIONS_NUM_VARIABLES] {
+            for batch_size in [1, 3] {
+                for num_points in [0, 1, 6] {
+                    run_evaluations(num_variables, batch_size, num_points, false);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_evaluations_tampered() {
+        run_evaluations(MIN_EVALUATIONS_NUM_VARIABLES, 2, 6, true);
+    }
+}
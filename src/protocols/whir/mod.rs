@@ -1,31 +1,45 @@
 mod batching;
 mod committer;
 mod config;
+mod deferred;
+mod evaluations;
+mod openings;
 mod prover;
+mod proximity;
+mod statement;
 mod verifier;
 
 pub use self::{
     committer::{Commitment, Witness},
     config::{Config, RoundConfig},
+    deferred::{DeferredClaims, OpenClaims},
+    proximity::FinalClaim,
+    statement::Statement,
 };
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
 
-    use ark_ff::Field;
+    use ark_ff::{FftField, Field, Fp6ConfigWrapper, UniformRand};
 
     use super::*;
     use crate::{
         algebra::{
-            embedding::Basefield,
-            fields::{Field64, Field64_2},
+            embedding::{Basefield, Compose, CubicExtension, Embedding, Identity, Subfield},
+            fields::{
+                BabyBear4, BabyBear5, BinaryField128, BinaryField16, BinaryField32, BinaryField8,
+                F6Config64, Field64, Field64_2, KoalaBear4, KoalaBear5, Mersenne31Complex,
+            },
             linear_form::{Covector, Evaluate, LinearForm, MultilinearExtension},
-            MultilinearPoint,
+            ntt::NttContext,
+            Basis, MultilinearPoint, MultilinearPolynomial,
         },
         hash,
         parameters::{FoldingFactor, MultivariateParameters, ProtocolParameters, SoundnessType},
-        transcript::{codecs::Empty, DomainSeparator, ProverState, VerifierState},
+        transcript::{
+            codecs::Empty, Codec, DomainSeparator, ProverState, StreamLimits, VerifierState,
+        },
         utils::test_serde,
     };
 
@@ -165,6 +179,8 @@ mod tests {
                 &linear_form_refs,
                 &evaluations,
             )
+            .unwrap()
+            .check_with(&linear_form_refs)
             .unwrap();
     }
 
@@ -257,6 +273,89 @@ mod tests {
         }
     }
 
+    /// The initial sumcheck can fold its first rounds with a univariate skip.
+    #[test]
+    fn test_whir_univariate_skip() {
+        let mut rng = ark_std::test_rng();
+        for (num_variables, folding_factor, univariate_skip) in [(4, 2, 1), (6, 3, 2), (6, 3, 3)] {
+            let whir_params = ProtocolParameters {
+                initial_statement: true,
+                security_level: 32,
+                pow_bits: 0,
+                folding_factor: FoldingFactor::Constant(folding_factor),
+                soundness_type: SoundnessType::ConjectureList,
+                starting_log_inv_rate: 1,
+                batch_size: 1,
+                hash_id: hash::SHA2,
+            };
+            let params = Config::new(MultivariateParameters::new(num_variables), &whir_params);
+            let round_pow = params.initial_sumcheck.round_pow;
+            let params = params.with_univariate_skip(univariate_skip);
+            assert_eq!(params.initial_sumcheck.univariate_skip, univariate_skip);
+            // Only the skip round pays for its larger soundness error.
+            assert_eq!(params.initial_sumcheck.round_pow, round_pow);
+            let skip_pow = params.initial_sumcheck.skip_pow.unwrap();
+            assert!(skip_pow.difficulty() >= round_pow.difficulty());
+
+            let vector = (0..1 << num_variables)
+                .map(|_| F::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let point = MultilinearPoint::<EF>::rand(&mut rng, num_variables);
+            let covector = (0..1 << num_variables)
+                .map(|_| EF::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let build_forms = || -> Vec<Box<dyn LinearForm<EF>>> {
+                vec![
+                    Box::new(MultilinearExtension::new(point.0.clone())),
+                    Box::new(Covector {
+                        deferred: true,
+                        vector: covector.clone(),
+                    }),
+                ]
+            };
+            let linear_forms = build_forms();
+            let evaluations = linear_forms
+                .iter()
+                .map(|form| Covector::from(form.as_ref()).evaluate(params.embedding(), &vector))
+                .collect::<Vec<_>>();
+            let linear_form_refs = linear_forms
+                .iter()
+                .map(|l| l.as_ref() as &dyn LinearForm<EF>)
+                .collect::<Vec<_>>();
+
+            let ds = DomainSeparator::protocol(&params)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+            let mut prover_state = ProverState::new_std(&ds);
+            let witness = params.commit(&mut prover_state, &[&vector]);
+            let (prover_point, _) = params.prove(
+                &mut prover_state,
+                vec![Cow::Borrowed(vector.as_slice())],
+                vec![Cow::Owned(witness)],
+                build_forms(),
+                Cow::Borrowed(evaluations.as_slice()),
+            );
+            assert_eq!(
+                prover_point.num_variables(),
+                num_variables - univariate_skip + 1
+            );
+
+            let proof = prover_state.proof();
+            let mut verifier_state = VerifierState::new_std(&ds, &proof);
+            let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+            let claims = params
+                .verify(
+                    &mut verifier_state,
+                    &[&commitment],
+                    &linear_form_refs,
+                    &evaluations,
+                )
+                .unwrap();
+            assert_eq!(claims.univariate_skip(), univariate_skip);
+            assert_eq!(claims.check_with(&linear_form_refs).unwrap(), prover_point);
+        }
+    }
+
     /// Test batch proving with multiple independent polynomials and statements.
     ///
     /// Creates N separate polynomials, commits to each independently, and uses RLC to batch-prove
@@ -370,9 +469,393 @@ mod tests {
                 &linear_form_refs,
                 &evaluations,
             )
+            .unwrap()
+            .check_with(&linear_form_refs)
             .unwrap();
     }
 
+    /// Run WHIR committing over `M::Source` with challenges in `M::Target`.
+    fn run_whir_embedding<M>(embedding: M, security_level: usize)
+    where
+        M: Embedding,
+        M::Source: FftField,
+        M::Target: FftField + Codec,
+    {
+        let num_variables = 8;
+        let mut rng = ark_std::test_rng();
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(4),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size: 1,
+            hash_id: hash::SHA2,
+        };
+        let params = Config::with_embedding(
+            embedding,
+            MultivariateParameters::new(num_variables),
+            &whir_params,
+        );
+        test_serde(&params);
+
+        let vector = (0..1 << num_variables)
+            .map(|_| M::Source::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let point = MultilinearPoint::rand(&mut rng, num_variables);
+        let linear_form = MultilinearExtension::new(point.0.clone());
+        let evaluation = linear_form.evaluate(params.embedding(), &vector);
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &[&vector]);
+        params.prove(
+            &mut prover_state,
+            vec![Cow::Borrowed(vector.as_slice())],
+            vec![Cow::Owned(witness)],
+            vec![Box::new(MultilinearExtension::new(point.0))],
+            Cow::Owned(vec![evaluation]),
+        );
+        let proof = prover_state.proof();
+
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        let linear_forms: [&dyn LinearForm<M::Target>; 1] = [&linear_form];
+        params
+            .verify(
+                &mut verifier_state,
+                &[&commitment],
+                &linear_forms,
+                &[evaluation],
+            )
+            .unwrap()
+            .check_with(&linear_forms)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_whir_31_bit_fields() {
+        run_whir_embedding(Basefield::<BabyBear4>::new(), 100);
+        run_whir_embedding(Basefield::<KoalaBear4>::new(), 100);
+        run_whir_embedding(Basefield::<BabyBear5>::new(), 100);
+        run_whir_embedding(Basefield::<KoalaBear5>::new(), 100);
+    }
+
+    #[test]
+    fn test_whir_binary_fields() {
+        run_whir_embedding(Subfield::<BinaryField8, BinaryField128>::new(), 100);
+        run_whir_embedding(Subfield::<BinaryField16, BinaryField128>::new(), 100);
+        run_whir_embedding(Subfield::<BinaryField32, BinaryField128>::new(), 100);
+        run_whir_embedding(Subfield::<BinaryField128, BinaryField128>::new(), 100);
+    }
+
+    #[test]
+    fn test_whir_tower_extensions() {
+        // Commit over Goldilocks² with challenges in Goldilocks⁶.
+        run_whir_embedding(CubicExtension::<Fp6ConfigWrapper<F6Config64>>::new(), 100);
+        run_whir_embedding(
+            Compose::new(
+                Basefield::<Field64_2>::new(),
+                CubicExtension::<Fp6ConfigWrapper<F6Config64>>::new(),
+            ),
+            100,
+        );
+        run_whir_embedding(
+            Compose::new(
+                Subfield::<BinaryField8, BinaryField32>::new(),
+                Subfield::<BinaryField32, BinaryField128>::new(),
+            ),
+            100,
+        );
+    }
+
+    #[test]
+    fn test_whir_mersenne31_complex() {
+        // The base field has no NTT, so commit over the complex extension itself. At 62 bits
+        // the field is too small for 100 bits of security without grinding.
+        run_whir_embedding(Identity::<Mersenne31Complex>::new(), 32);
+    }
+
+    fn run_whir_committer_options(
+        num_variables: usize,
+        folding_factor: usize,
+        batch_size: usize,
+        include_covector: bool,
+        basis: Basis,
+        coset_offset: F,
+    ) {
+        let mut rng = ark_std::test_rng();
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(folding_factor),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size,
+            hash_id: hash::SHA2,
+        };
+        let params = Config::<EF>::new(MultivariateParameters::new(num_variables), &whir_params)
+            .with_basis(basis)
+            .with_coset_offset(coset_offset);
+        test_serde(&params);
+
+        // Hypercube evaluations of random multilinear polynomials.
+        let vectors = (0..batch_size)
+            .map(|_| {
+                (0..1 << num_variables)
+                    .map(|_| F::rand(&mut rng))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let points = (0..2)
+            .map(|_| MultilinearPoint::rand(&mut rng, num_variables))
+            .collect::<Vec<_>>();
+        let linear_forms = build_prove_forms(&points, num_variables, include_covector);
+        let evaluations = linear_forms
+            .iter()
+            .flat_map(|linear_form| {
+                let covector = Covector::from(linear_form.as_ref());
+                vectors
+                    .iter()
+                    .map(|vector| covector.evaluate(params.embedding(), vector))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let vector_refs = vectors.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let witness = params.commit(&mut prover_state, &vector_refs);
+        params.prove(
+            &mut prover_state,
+            vector_refs.iter().map(|v| Cow::Borrowed(*v)).collect(),
+            vec![Cow::Owned(witness)],
+            build_prove_forms(&points, num_variables, include_covector),
+            Cow::Borrowed(evaluations.as_slice()),
+        );
+        let proof = prover_state.proof();
+
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        let linear_form_refs = linear_forms
+            .iter()
+            .map(|l| l.as_ref() as &dyn LinearForm<EF>)
+            .collect::<Vec<_>>();
+        params
+            .verify(
+                &mut verifier_state,
+                &[&commitment],
+                &linear_form_refs,
+                &evaluations,
+            )
+            .unwrap()
+            .check_with(&linear_form_refs)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_whir_evaluation_basis() {
+        for folding_factor in [1, 2, 3] {
+            for num_variables in folding_factor..=3 * folding_factor {
+                for batch_size in [1, 2] {
+                    for include_covector in [false, true] {
+                        run_whir_committer_options(
+                            num_variables,
+                            folding_factor,
+                            batch_size,
+                            include_covector,
+                            Basis::Evaluations,
+                            F::ONE,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_whir_coset_offset() {
+        for (num_variables, folding_factor) in [(2, 1), (6, 2), (9, 3)] {
+            for basis in [Basis::Coefficients, Basis::Evaluations] {
+                run_whir_committer_options(
+                    num_variables,
+                    folding_factor,
+                    2,
+                    true,
+                    basis,
+                    F::GENERATOR,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_whir_polynomials() {
+        let mut rng = ark_std::test_rng();
+        let num_variables = 6;
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size: 2,
+            hash_id: hash::SHA2,
+        };
+        for (basis, other) in [
+            (Basis::Coefficients, Basis::Evaluations),
+            (Basis::Evaluations, Basis::Coefficients),
+        ] {
+            let params =
+                Config::<EF>::new(MultivariateParameters::new(num_variables), &whir_params)
+                    .with_basis(basis);
+            // Polynomials given in the other basis are converted on commit.
+            let polynomials = (0..2)
+                .map(|_| MultilinearPolynomial::<F>::rand(&mut rng, num_variables, other))
+                .collect::<Vec<_>>();
+            let polynomial_refs = polynomials.iter().collect::<Vec<_>>();
+            let points = (0..2)
+                .map(|_| MultilinearPoint::rand(&mut rng, num_variables))
+                .collect::<Vec<_>>();
+            let linear_forms = build_prove_forms(&points, num_variables, false);
+            let evaluations = linear_forms
+                .iter()
+                .zip(&points)
+                .flat_map(|(linear_form, point)| {
+                    let covector = Covector::from(linear_form.as_ref());
+                    polynomials
+                        .iter()
+                        .map(|polynomial| {
+                            let value =
+                                covector.evaluate(params.embedding(), &polynomial.values_in(basis));
+                            if basis == Basis::Evaluations {
+                                assert_eq!(
+                                    polynomial.mixed_evaluate(params.embedding(), point),
+                                    value
+                                );
+                            }
+                            value
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            let ds = DomainSeparator::protocol(&params)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+            let mut prover_state = ProverState::new_std(&ds);
+            let witness = params.commit_polynomials(
+                NttContext::global(),
+                &mut prover_state,
+                &polynomial_refs,
+            );
+            params.prove_polynomials(
+                NttContext::global(),
+                &mut prover_state,
+                &polynomial_refs,
+                vec![Cow::Owned(witness)],
+                build_prove_forms(&points, num_variables, false),
+                Cow::Borrowed(evaluations.as_slice()),
+            );
+            let proof = prover_state.proof();
+
+            let mut verifier_state = VerifierState::new_std(&ds, &proof);
+            let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+            let linear_form_refs = linear_forms
+                .iter()
+                .map(|l| l.as_ref() as &dyn LinearForm<EF>)
+                .collect::<Vec<_>>();
+            params
+                .verify(
+                    &mut verifier_state,
+                    &[&commitment],
+                    &linear_form_refs,
+                    &evaluations,
+                )
+                .unwrap()
+                .check_with(&linear_form_refs)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_whir_ntt_context() {
+        let mut rng = ark_std::test_rng();
+        let num_variables = 8;
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size: 1,
+            hash_id: hash::SHA2,
+        };
+        let params = Config::<EF>::new(MultivariateParameters::new(num_variables), &whir_params);
+        let vector = (0..1 << num_variables)
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let points = (0..2)
+            .map(|_| MultilinearPoint::rand(&mut rng, num_variables))
+            .collect::<Vec<_>>();
+        let linear_forms = build_prove_forms(&points, num_variables, false);
+        let evaluations = linear_forms
+            .iter()
+            .map(|linear_form| {
+                Covector::from(linear_form.as_ref()).evaluate(params.embedding(), &vector)
+            })
+            .collect::<Vec<_>>();
+        let linear_form_refs = linear_forms
+            .iter()
+            .map(|l| l.as_ref() as &dyn LinearForm<EF>)
+            .collect::<Vec<_>>();
+
+        // A pre-warmed context is not extended while proving, a context without budget
+        // retains nothing.
+        let warm = NttContext::default();
+        params.precompute_ntt(&warm);
+        let warm_usage = warm.memory_usage();
+        assert!(warm_usage > 0);
+        for (context, usage) in [(warm, warm_usage), (NttContext::new(0), 0)] {
+            let ds = DomainSeparator::protocol(&params)
+                .session(&format!("Test at {}:{}", file!(), line!()))
+                .instance(&Empty);
+            let mut prover_state = ProverState::new_std(&ds);
+            let witness = params.commit_with_context(&context, &mut prover_state, &[&vector]);
+            params.prove_with_context(
+                &context,
+                &mut prover_state,
+                vec![Cow::Borrowed(vector.as_slice())],
+                vec![Cow::Owned(witness)],
+                build_prove_forms(&points, num_variables, false),
+                Cow::Borrowed(evaluations.as_slice()),
+            );
+            assert_eq!(context.memory_usage(), usage);
+            let proof = prover_state.proof();
+
+            let mut verifier_state = VerifierState::new_std(&ds, &proof);
+            let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+            params
+                .verify(
+                    &mut verifier_state,
+                    &[&commitment],
+                    &linear_form_refs,
+                    &evaluations,
+                )
+                .unwrap()
+                .check_with(&linear_form_refs)
+                .unwrap();
+        }
+    }
+
     #[test]
     fn test_whir_batch_1() {
         // Test with different configurations
@@ -642,12 +1125,14 @@ mod tests {
             .iter()
             .map(|l| l.as_ref() as &dyn LinearForm<EF>)
             .collect::<Vec<_>>();
-        let verify_result = params.verify(
-            &mut verifier_state,
-            &commitment_refs,
-            &linear_form_refs,
-            &evaluations,
-        );
+        let verify_result = params
+            .verify(
+                &mut verifier_state,
+                &commitment_refs,
+                &linear_form_refs,
+                &evaluations,
+            )
+            .and_then(|claims| claims.check_with(&linear_form_refs));
         assert!(
             verify_result.is_ok(),
             "Batch verification with batch_size={} failed: {:?}",
@@ -679,4 +1164,82 @@ mod tests {
             }
         }
     }
+
+    /// Test that a proof verifies when read incrementally, and that truncated streams are
+    /// rejected.
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_whir_streaming_verifier() {
+        let num_variables = 6;
+        let mut rng = ark_std::test_rng();
+
+        let mv_params = MultivariateParameters::new(num_variables);
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size: 1,
+            hash_id: hash::SHA2,
+        };
+        let params = Config::<EF>::new(mv_params, &whir_params);
+
+        let vector = (0..1 << num_variables).map(F::from).collect::<Vec<_>>();
+        let point = MultilinearPoint::rand(&mut rng, num_variables);
+        let linear_form = MultilinearExtension::new(point.0.clone());
+        let evaluations = vec![linear_form.evaluate(params.embedding(), &vector)];
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &[&vector]);
+        params.prove(
+            &mut prover_state,
+            vec![Cow::Borrowed(vector.as_slice())],
+            vec![Cow::Owned(witness)],
+            build_prove_forms(&[point], num_variables, false),
+            Cow::Borrowed(evaluations.as_slice()),
+        );
+        let proof = prover_state.proof();
+
+        // A small buffer forces many refills.
+        let limits = StreamLimits {
+            buffer_size: 64,
+            ..StreamLimits::default()
+        };
+        let verify = |narg_string: &[u8], hints: &[u8], limits: StreamLimits| {
+            let mut verifier_state =
+                VerifierState::from_reader_std(&ds, narg_string, hints, limits);
+            let commitment = params.receive_commitment(&mut verifier_state)?;
+            params
+                .verify(
+                    &mut verifier_state,
+                    &[&commitment],
+                    &[&linear_form],
+                    &evaluations,
+                )?
+                .check_with(&[&linear_form])?;
+            verifier_state.check_eof()
+        };
+
+        assert!(verify(&proof.narg_string, &proof.hints, limits).is_ok());
+        assert!(verify(
+            &proof.narg_string,
+            &proof.hints[..proof.hints.len() - 1],
+            limits
+        )
+        .is_err());
+        assert!(verify(
+            &proof.narg_string,
+            &proof.hints,
+            StreamLimits {
+                max_hint_bytes: proof.hints.len() as u64 - 1,
+                ..limits
+            }
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,197 @@
+//! Openings of committed vectors at caller-chosen indices.
+//!
+//! Each index `i` becomes a [`SparseCovector`] selecting `vector[i]`. All
+//! indices and vectors are batched into a single WHIR proof.
+
+use std::borrow::Cow;
+
+use ark_ff::FftField;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use super::{committer::Witness, config::Config, Commitment};
+use crate::{
+    algebra::{
+        embedding::Embedding,
+        linear_form::{LinearForm, SparseCovector},
+    },
+    hash::Hash,
+    transcript::{
+        codecs::U64, Codec, Decoding, DuplexSpongeInterface, ProverMessage, ProverState,
+        VerificationResult, VerifierState,
+    },
+    verify,
+};
+
+impl<F, M> Config<F, M>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// Prove the entries of the committed `vectors` at the given `indices`.
+    ///
+    /// Arguments are as in [`Self::prove`]. Returns the opened values in
+    /// row-major order with one row per index and one column per vector.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn prove_entries<'a, H, R>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        vectors: Vec<Cow<'a, [M::Source]>>,
+        witnesses: Vec<Cow<'a, Witness<F, M>>>,
+        indices: &[usize],
+    ) -> Vec<M::Source>
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        assert!(indices.iter().all(|&index| index < self.initial_size()));
+        let values = indices
+            .iter()
+            .flat_map(|&index| vectors.iter().map(move |vector| vector[index]))
+            .collect::<Vec<_>>();
+        let evaluations = values.iter().map(|&v| self.embedding().map(v)).collect();
+        let linear_forms = self
+            .entry_forms(indices)
+            .into_iter()
+            .map(|form| Box::new(form) as Box<dyn LinearForm<F>>)
+            .collect();
+        self.prove(
+            prover_state,
+            vectors,
+            witnesses,
+            linear_forms,
+            Cow::Owned(evaluations),
+        );
+        values
+    }
+
+    /// Verify the entries of the committed vectors at the given `indices`.
+    ///
+    /// The `values` are in the order returned by [`Self::prove_entries`].
+    pub fn verify_entries<H>(
+        &self,
+        verifier_state: &mut VerifierState<'_, H>,
+        commitments: &[&Commitment<F>],
+        indices: &[usize],
+        values: &[M::Source],
+    ) -> VerificationResult<()>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        verify!(indices.iter().all(|&index| index < self.initial_size()));
+        let evaluations = values
+            .iter()
+            .map(|&v| self.embedding().map(v))
+            .collect::<Vec<_>>();
+        let forms = self.entry_forms(indices);
+        let form_refs = forms
+            .iter()
+            .map(|form| form as &dyn LinearForm<F>)
+            .collect::<Vec<_>>();
+        self.verify(verifier_state, commitments, &form_refs, &evaluations)?
+            .check_with(&form_refs)?;
+        Ok(())
+    }
+
+    /// Linear forms selecting the entries at `indices`.
+    fn entry_forms(&self, indices: &[usize]) -> Vec<SparseCovector<F>> {
+        indices
+            .iter()
+            .map(|&index| SparseCovector::unit(self.initial_size(), index))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use ark_ff::UniformRand;
+
+    use super::super::Config;
+    use crate::{
+        algebra::fields::{Field64, Field64_2},
+        hash,
+        parameters::{FoldingFactor, MultivariateParameters, ProtocolParameters, SoundnessType},
+        transcript::{codecs::Empty, DomainSeparator, ProverState, VerifierState},
+    };
+
+    /// Field type used in the tests.
+    type F = Field64;
+
+    /// Extension field type used in the tests.
+    type EF = Field64_2;
+
+    fn run_entries(num_variables: usize, batch_size: usize, indices: &[usize], tamper: bool) {
+        let mut rng = ark_std::test_rng();
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size,
+            hash_id: hash::SHA2,
+        };
+        let params = Config::<EF>::new(MultivariateParameters::new(num_variables), &whir_params);
+
+        let vectors: Vec<Vec<F>> = (0..batch_size)
+            .map(|_| (0..1 << num_variables).map(|_| F::rand(&mut rng)).collect())
+            .collect();
+        let vec_refs = vectors.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &vec_refs);
+        let mut values = params.prove_entries(
+            &mut prover_state,
+            vec_refs.iter().map(|v| Cow::Borrowed(*v)).collect(),
+            vec![Cow::Owned(witness)],
+            indices,
+        );
+        for (i, &index) in indices.iter().enumerate() {
+            for (j, vector) in vectors.iter().enumerate() {
+                assert_eq!(values[i * batch_size + j], vector[index]);
+            }
+        }
+        if tamper {
+            values[0] += F::from(1);
+        }
+
+        let proof = prover_state.proof();
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        let result = params.verify_entries(&mut verifier_state, &[&commitment], indices, &values);
+        assert_eq!(result.is_ok(), !tamper);
+    }
+
+    #[test]
+    fn test_entries() {
+        for num_variables in [2, 5, 6] {
+            for batch_size in [1, 3] {
+                run_entries(num_variables, batch_size, &[], false);
+                run_entries(num_variables, batch_size, &[0, 3, 1, 3], false);
+            }
+        }
+        run_entries(6, 2, &[63, 17, 0, 42, 5], false);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_entries_tampered() {
+        run_entries(5, 2, &[7, 30], true);
+    }
+}
@@ -5,16 +5,17 @@ use ark_std::rand::{CryptoRng, RngCore};
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
-use super::{committer::Witness, config::Config};
+use super::{committer::Witness, config::Config, FinalClaim};
 use crate::{
     algebra::{
         dot,
-        embedding::Embedding,
+        embedding::{Embedding, Identity},
         lift,
-        linear_form::{Covector, Evaluate, LinearForm, UnivariateEvaluation},
-        mixed_scalar_mul_add,
-        sumcheck::fold,
-        tensor_product, MultilinearPoint,
+        linear_form::{Covector, Evaluate, LinearForm, MultilinearExtension, UnivariateEvaluation},
+        mixed_dot, mixed_scalar_mul_add,
+        ntt::NttContext,
+        sumcheck::{fold_weighted, mixed_fold_weighted, ProductCovector},
+        tensor_product, Basis, MultilinearPoint, MultilinearPolynomial,
     },
     hash::Hash,
     protocols::{geometric_challenge::geometric_challenge, irs_commit},
@@ -25,6 +26,14 @@ use crate::{
     utils::zip_strict,
 };
 
+/// Largest number of factored covectors for which the initial sumcheck keeps them factored.
+///
+/// Each factored covector costs a pass over the vector per round, where the materialized
+/// covector costs a single pass after materializing. At size 2^20 with four rounds the
+/// factored form is about 2.5× faster for one covector, 1.4× for four and breaks even
+/// around eight (see `benches/sumcheck.rs`).
+pub(super) const MAX_PRODUCT_COVECTORS: usize = 4;
+
 enum RoundWitness<'a, F: FftField, M: Embedding<Target = F>>
 where
     M::Source: FftField,
@@ -53,8 +62,9 @@ where
     /// equal to the `linear_forms.len()` and the number of columns equal to
     /// `vectors.len()`.
     ///
-    #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    #[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
+    /// If the linear forms are too many [`MultilinearExtension`]s to stay factored in the
+    /// initial sumcheck, they are first reduced to a single one with
+    /// [`Self::evaluations_sumcheck`].
     pub fn prove<'a, H, R>(
         &self,
         prover_state: &mut ProverState<H, R>,
@@ -63,6 +73,106 @@ where
         linear_forms: Vec<Box<dyn LinearForm<F>>>,
         evaluations: Cow<'a, [F]>,
     ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        self.prove_with_context(
+            NttContext::global(),
+            prover_state,
+            vectors,
+            witnesses,
+            linear_forms,
+            evaluations,
+        )
+    }
+
+    /// Same as [`Self::prove`], using the tables in `context` for the round commitments
+    /// and the univariate skip of the sumchecks.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove_with_context<'a, H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        vectors: Vec<Cow<'a, [M::Source]>>,
+        witnesses: Vec<Cow<'a, Witness<F, M>>>,
+        linear_forms: Vec<Box<dyn LinearForm<F>>>,
+        evaluations: Cow<'a, [F]>,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        let (claim, deferred) = self.prove_inner(
+            context,
+            prover_state,
+            vectors,
+            witnesses,
+            linear_forms,
+            evaluations,
+        );
+        (claim.point, deferred)
+    }
+
+    /// Same as [`Self::prove_with_context`] for polynomials committed with
+    /// [`Self::commit_polynomials`].
+    ///
+    /// The linear forms apply to the values of the polynomials in the
+    /// [`irs_commit::Config::basis`] of the initial commitment. With
+    /// [`Basis::Evaluations`] a [`MultilinearExtension`] form evaluates the polynomials.
+    pub fn prove_polynomials<'a, H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        polynomials: &[&'a MultilinearPolynomial<M::Source>],
+        witnesses: Vec<Cow<'a, Witness<F, M>>>,
+        linear_forms: Vec<Box<dyn LinearForm<F>>>,
+        evaluations: Cow<'a, [F]>,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        let vectors = polynomials
+            .iter()
+            .map(|polynomial| polynomial.values_in(self.initial_committer.basis))
+            .collect();
+        self.prove_with_context(
+            context,
+            prover_state,
+            vectors,
+            witnesses,
+            linear_forms,
+            evaluations,
+        )
+    }
+
+    /// Implementation of [`Self::prove`] that also returns the final folded claim.
+    #[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
+    pub(super) fn prove_inner<'a, H, R>(
+        &self,
+        context: &NttContext,
+        prover_state: &mut ProverState<H, R>,
+        vectors: Vec<Cow<'a, [M::Source]>>,
+        witnesses: Vec<Cow<'a, Witness<F, M>>>,
+        linear_forms: Vec<Box<dyn LinearForm<F>>>,
+        evaluations: Cow<'a, [F]>,
+    ) -> (FinalClaim<F>, Vec<F>)
     where
         H: DuplexSpongeInterface,
         R: RngCore + CryptoRng,
@@ -98,7 +208,7 @@ where
         }
         if vectors.is_empty() {
             // TODO: Should we draw a random evaluation point of the right size?
-            return (MultilinearPoint::default(), Vec::new());
+            return (FinalClaim::default(), Vec::new());
         }
 
         // Complete evaluations of EVERY vector at EVERY linear form.
@@ -110,7 +220,9 @@ where
             let mut vector_offset = 0;
             for witness in &witnesses {
                 for (oods_eval, oods_row) in zip_strict(
-                    witness.out_of_domain().evaluators(self.initial_size()),
+                    witness
+                        .out_of_domain()
+                        .evaluators(self.initial_size(), self.initial_committer.basis),
                     witness.out_of_domain().rows(),
                 ) {
                     for (j, vector) in vectors.iter().enumerate() {
@@ -137,18 +249,71 @@ where
         // Random linear combination of the vectors.
         let mut vector_rlc_coeffs: Vec<F> = geometric_challenge(prover_state, num_vectors);
         assert_eq!(vector_rlc_coeffs[0], F::ONE);
-        // Recycle the first input as the accumulator (its coefficient is always ONE).
+        let batching_coeffs = vector_rlc_coeffs.clone();
+        // A single vector is kept in the source field until the first fold.
         let mut vectors = vectors.into_iter();
         let first = vectors.next().expect("non-empty");
-        let mut vector = match first {
-            Cow::Borrowed(slice) => lift(self.embedding(), slice),
-            Cow::Owned(vec) => self.embedding().map_vec(vec),
+        let (mut source_vector, mut vector) = if num_vectors == 1 {
+            (Some(first), Vec::new())
+        } else {
+            // Recycle the first input as the accumulator (its coefficient is always ONE).
+            let mut vector = match first {
+                Cow::Borrowed(slice) => lift(self.embedding(), slice),
+                Cow::Owned(vec) => self.embedding().map_vec(vec),
+            };
+            for (rlc_coeff, input_vector) in zip_strict(&vector_rlc_coeffs[1..], vectors) {
+                mixed_scalar_mul_add(self.embedding(), &mut vector, *rlc_coeff, &input_vector);
+            }
+            (None, vector)
+        };
+        let batched_dot = |vector: &[F], covector: &[F]| match &source_vector {
+            Some(source) => mixed_dot(self.embedding(), covector, &source[..]),
+            None => dot(vector, covector),
+        };
+
+        // Evaluations of the batched vector at each linear form.
+        let evaluations = evaluations
+            .chunks_exact(num_vectors)
+            .map(|row| dot(&vector_rlc_coeffs, row))
+            .collect::<Vec<F>>();
+
+        // Reduce evaluations at several points to a single point.
+        let points = self.evaluation_points(
+            &linear_forms
+                .iter()
+                .map(|linear_form| linear_form.as_ref())
+                .collect::<Vec<_>>(),
+            oods_evals.len(),
+        );
+        let (linear_forms, evaluations) = match points {
+            Some(points) => {
+                let (linear_form, evaluation) = match &source_vector {
+                    Some(source) => self.prove_evaluations(
+                        context,
+                        prover_state,
+                        self.embedding(),
+                        &source[..],
+                        &points,
+                        &evaluations,
+                    ),
+                    None => self.prove_evaluations(
+                        context,
+                        prover_state,
+                        &Identity::<F>::new(),
+                        &vector,
+                        &points,
+                        &evaluations,
+                    ),
+                };
+                let linear_forms: Vec<Box<dyn LinearForm<F>>> = vec![Box::new(linear_form)];
+                (linear_forms, vec![evaluation])
+            }
+            None => (linear_forms, evaluations),
         };
-        for (rlc_coeff, input_vector) in zip_strict(&vector_rlc_coeffs[1..], vectors) {
-            mixed_scalar_mul_add(self.embedding(), &mut vector, *rlc_coeff, &input_vector);
-        }
 
         let mut prev_witness: RoundWitness<'a, F, M> = RoundWitness::Initial(witnesses);
+        // Codeword allocation of an opened round witness, reused by the next commitment.
+        let mut spare_matrix: Vec<F> = Vec::new();
 
         // Random linear combination of the constraints.
         let constraint_rlc_coeffs: Vec<F> =
@@ -157,8 +322,20 @@ where
         // TODO: Flip order.
         let (oods_rlc_coeffs, initial_forms_rlc_coeffs) =
             constraint_rlc_coeffs.split_at(oods_evals.len());
+        // Point evaluations and out-of-domain samples can stay factored in the initial sumcheck.
+        let product_covectors = if has_constraints {
+            self.product_covectors(
+                &linear_forms,
+                initial_forms_rlc_coeffs,
+                &oods_evals,
+                oods_rlc_coeffs,
+            )
+        } else {
+            None
+        };
+        let materialize = has_constraints && product_covectors.is_none();
         // Recycle a Covector buffer as the initial accumulator.
-        let (mut covector, recycled_index) = if has_constraints {
+        let (mut covector, recycled_index) = if materialize {
             let found = initial_forms_rlc_coeffs
                 .iter()
                 .zip(linear_forms.iter())
@@ -178,29 +355,28 @@ where
         } else {
             (Vec::new(), None)
         };
-        for (i, (rlc_coeff, linear_form)) in
-            zip_strict(initial_forms_rlc_coeffs, &linear_forms).enumerate()
-        {
-            if Some(i) == recycled_index {
-                continue;
+        if materialize {
+            for (i, (rlc_coeff, linear_form)) in
+                zip_strict(initial_forms_rlc_coeffs, &linear_forms).enumerate()
+            {
+                if Some(i) == recycled_index {
+                    continue;
+                }
+                linear_form.accumulate(&mut covector, *rlc_coeff);
             }
-            linear_form.accumulate(&mut covector, *rlc_coeff);
         }
 
         // Compute "The Sum"
-        let mut the_sum: F = zip_strict(
-            initial_forms_rlc_coeffs,
-            evaluations.chunks_exact(num_vectors),
-        )
-        .map(|(poly_coeff, row)| *poly_coeff * dot(&vector_rlc_coeffs, row))
-        .sum();
+        let mut the_sum = dot(initial_forms_rlc_coeffs, &evaluations);
 
         drop(evaluations);
 
-        debug_assert!(!has_constraints || dot(&vector, &covector) == the_sum);
+        debug_assert!(!materialize || batched_dot(&vector, &covector) == the_sum);
 
         // Add OODS constraints
-        UnivariateEvaluation::accumulate_many(&oods_evals, &mut covector, oods_rlc_coeffs);
+        if materialize {
+            UnivariateEvaluation::accumulate_many(&oods_evals, &mut covector, oods_rlc_coeffs);
+        }
         the_sum += zip_strict(oods_rlc_coeffs, oods_matrix.chunks_exact(num_vectors))
             .map(|(poly_coeff, row)| *poly_coeff * dot(&vector_rlc_coeffs, row))
             .sum::<F>();
@@ -208,70 +384,127 @@ where
         drop(oods_evals);
         drop(oods_matrix);
 
-        debug_assert!(!has_constraints || dot(&vector, &covector) == the_sum);
+        debug_assert!(!materialize || batched_dot(&vector, &covector) == the_sum);
 
         // Run initial sumcheck on batched vectors with combined statement
         let mut folding_randomness = if constraint_rlc_coeffs.is_empty() {
             // There are no constraints yet, so we can skip the sumcheck.
             // (If we did run it, all sumcheck vectors would be constant zero)
             // TODO: Don't compute evaluations and constraints in the first place.
-            let folding_randomness = (0..self.initial_sumcheck.num_rounds)
-                .map(|_| prover_state.verifier_message())
-                .collect();
+            let folding_randomness = MultilinearPoint(
+                (0..self.initial_sumcheck.num_challenges())
+                    .map(|_| prover_state.verifier_message())
+                    .collect(),
+            );
             self.initial_sumcheck.round_pow.prove(prover_state);
             // Fold vector
-            for &f in &folding_randomness {
-                fold(&mut vector, f);
+            let weights = self.initial_sumcheck.folding_weights(&folding_randomness);
+            if let Some(source) = source_vector.take() {
+                vector = mixed_fold_weighted(self.embedding(), &source[..], &weights);
+            } else {
+                fold_weighted(&mut vector, &weights);
             }
             // Covector must be all zeros.
             covector = vec![F::ZERO; self.initial_sumcheck.final_size()];
-            MultilinearPoint(folding_randomness)
+            folding_randomness
+        } else if let Some(product_covectors) = product_covectors {
+            let (folding_randomness, folded_covector) = match &source_vector {
+                Some(source) => {
+                    let (folding_randomness, folded_vector, folded_covector) =
+                        self.initial_sumcheck.prove_product_mixed(
+                            context,
+                            prover_state,
+                            self.embedding(),
+                            &source[..],
+                            product_covectors,
+                            &mut the_sum,
+                        );
+                    vector = folded_vector;
+                    (folding_randomness, folded_covector)
+                }
+                None => self.initial_sumcheck.prove_product(
+                    context,
+                    prover_state,
+                    &mut vector,
+                    product_covectors,
+                    &mut the_sum,
+                ),
+            };
+            covector = folded_covector;
+            folding_randomness
+        } else if let Some(source) = &source_vector {
+            let (folding_randomness, folded_vector) = self.initial_sumcheck.prove_mixed(
+                context,
+                prover_state,
+                self.embedding(),
+                &source[..],
+                &mut covector,
+                &mut the_sum,
+            );
+            vector = folded_vector;
+            folding_randomness
         } else {
-            self.initial_sumcheck
-                .prove(prover_state, &mut vector, &mut covector, &mut the_sum)
+            self.initial_sumcheck.prove(
+                context,
+                prover_state,
+                &mut vector,
+                &mut covector,
+                &mut the_sum,
+            )
         };
+        drop(source_vector);
 
         let mut randomness_vec = Vec::with_capacity(self.initial_num_variables());
         randomness_vec.extend(folding_randomness.0.iter().copied());
+        let mut folding_weights = self.initial_sumcheck.folding_weights(&folding_randomness);
         debug_assert_eq!(dot(&vector, &covector), the_sum);
 
         // Execute standard WHIR rounds on the batched vectors
         for (round_index, round_config) in self.round_configs.iter().enumerate() {
             // Commit to the vector, this generates out-of-domain evaluations.
-            let new_witness = round_config.irs_committer.commit(prover_state, &[&vector]);
+            let new_witness = round_config.irs_committer.commit_with_buffer(
+                context,
+                prover_state,
+                &[&vector],
+                std::mem::take(&mut spare_matrix),
+            );
 
             // Proof of work before in-domain challenges
             round_config.pow.prove(prover_state);
 
             // Open the previous round's witness.
-            let in_domain = match prev_witness {
+            let (in_domain, basis) = match prev_witness {
                 RoundWitness::Initial(init_witnesses) => {
                     let witness_refs: Vec<&_> = init_witnesses.iter().map(|c| &**c).collect();
-                    self.initial_committer
+                    let in_domain = self
+                        .initial_committer
                         .open(prover_state, &witness_refs)
-                        .lift(self.embedding())
+                        .lift(self.embedding());
+                    (in_domain, self.initial_committer.basis)
                 }
                 RoundWitness::Round(old_witness) => {
                     let prev_round_config = &self.round_configs[round_index - 1];
-                    prev_round_config
+                    let in_domain = prev_round_config
                         .irs_committer
-                        .open(prover_state, &[&old_witness])
+                        .open(prover_state, &[&old_witness]);
+                    spare_matrix = old_witness.matrix;
+                    (in_domain, prev_round_config.irs_committer.basis)
                 }
             };
 
             // Collect constraints for this round and RLC them in
             let stir_challenges = new_witness
                 .out_of_domain()
-                .evaluators(round_config.initial_size())
-                .chain(in_domain.evaluators(round_config.initial_size()))
+                .evaluators(
+                    round_config.initial_size(),
+                    round_config.irs_committer.basis,
+                )
+                .chain(in_domain.evaluators(round_config.initial_size(), basis))
                 .collect::<Vec<_>>();
             let stir_evaluations = new_witness
                 .out_of_domain()
                 .values(&[F::ONE])
-                .chain(in_domain.values(&tensor_product(
-                    &vector_rlc_coeffs,
-                    &folding_randomness.eq_weights(),
-                )))
+                .chain(in_domain.values(&tensor_product(&vector_rlc_coeffs, &folding_weights)))
                 .collect::<Vec<_>>();
             let stir_rlc_coeffs = geometric_challenge(prover_state, stir_challenges.len());
             UnivariateEvaluation::accumulate_many(
@@ -283,12 +516,16 @@ where
             debug_assert_eq!(dot(&vector, &covector), the_sum);
 
             // Run sumcheck for this round
-            folding_randomness =
-                round_config
-                    .sumcheck
-                    .prove(prover_state, &mut vector, &mut covector, &mut the_sum);
+            folding_randomness = round_config.sumcheck.prove(
+                context,
+                prover_state,
+                &mut vector,
+                &mut covector,
+                &mut the_sum,
+            );
 
             randomness_vec.extend(folding_randomness.0.iter().copied());
+            folding_weights = round_config.sumcheck.folding_weights(&folding_randomness);
             debug_assert_eq!(dot(&vector, &covector), the_sum);
 
             prev_witness = RoundWitness::Round(new_witness);
@@ -319,20 +556,71 @@ where
         }
 
         // Final sumcheck
-        let final_folding_randomness =
-            self.final_sumcheck
-                .prove(prover_state, &mut vector, &mut covector, &mut the_sum);
+        let final_folding_randomness = self.final_sumcheck.prove(
+            context,
+            prover_state,
+            &mut vector,
+            &mut covector,
+            &mut the_sum,
+        );
         randomness_vec.extend(final_folding_randomness.0.iter().copied());
+        assert_eq!(vector.len(), 1);
 
         // Hints for deferred constraints
         let constraint_eval = MultilinearPoint(randomness_vec);
         let deferred = linear_forms
             .into_iter()
             .filter(|w| w.deferred())
-            .map(|w| w.mle_evaluate(&constraint_eval.0))
+            .map(|w| {
+                self.initial_sumcheck
+                    .mle_evaluate(w.as_ref(), &constraint_eval.0)
+            })
             .collect();
         prover_state.prover_hint_ark(&deferred);
 
-        (constraint_eval, deferred)
+        let claim = FinalClaim {
+            point: constraint_eval,
+            batching_coeffs,
+            value: vector[0],
+        };
+        (claim, deferred)
+    }
+}
+
+impl<F, M> Config<F, M>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// The initial constraints as [`ProductCovector`]s, if all linear forms are point evaluations
+    /// and there are at most [`MAX_PRODUCT_COVECTORS`] of them.
+    fn product_covectors(
+        &self,
+        linear_forms: &[Box<dyn LinearForm<F>>],
+        linear_form_rlc_coeffs: &[F],
+        oods_evals: &[UnivariateEvaluation<F>],
+        oods_rlc_coeffs: &[F],
+    ) -> Option<Vec<ProductCovector<F>>> {
+        let num_variables = self.initial_num_variables();
+        let mut covectors = zip_strict(linear_forms, linear_form_rlc_coeffs)
+            .map(|(linear_form, &rlc_coeff)| {
+                (linear_form.as_ref() as &dyn Any)
+                    .downcast_ref::<MultilinearExtension<F>>()
+                    .map(|mle| ProductCovector::eq(&mle.point, rlc_coeff))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if covectors.len() + oods_evals.len() > MAX_PRODUCT_COVECTORS {
+            return None;
+        }
+        covectors.extend(
+            zip_strict(oods_evals, oods_rlc_coeffs).map(|(eval, &rlc_coeff)| match eval.basis {
+                Basis::Coefficients => {
+                    ProductCovector::univariate(eval.point, num_variables, rlc_coeff)
+                }
+                Basis::Evaluations => ProductCovector::eq(&eval.expanded_point().0, rlc_coeff),
+            }),
+        );
+        Some(covectors)
     }
 }
@@ -0,0 +1,190 @@
+//! WHIR as a low-degree test.
+//!
+//! Proves that committed vectors are close to the initial Reed-Solomon code
+//! without any linear form claims. See [`Config::proximity`] for the distance.
+
+use std::borrow::Cow;
+
+use ark_ff::{FftField, Field};
+use ark_std::rand::{CryptoRng, RngCore};
+
+use super::{committer::Witness, config::Config, Commitment};
+use crate::{
+    algebra::{embedding::Embedding, ntt::NttContext, MultilinearPoint},
+    hash::Hash,
+    transcript::{
+        codecs::U64, Codec, Decoding, DuplexSpongeInterface, ProverMessage, ProverState,
+        VerificationResult, VerifierState,
+    },
+};
+
+/// The claim left after all folding rounds.
+///
+/// States that the random linear combination of the committed vectors, with
+/// coefficients `batching_coeffs`, has multilinear extension `value` in
+/// `point`. The claim is not checked by WHIR and can be used for composition
+/// with other protocols.
+///
+/// With a univariate skip in the initial sumcheck the value is instead the
+/// evaluation folded as in [`crate::protocols::sumcheck::Config::mle_evaluate`].
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct FinalClaim<F: Field> {
+    /// The concatenated folding randomness.
+    pub point: MultilinearPoint<F>,
+
+    /// Coefficients of the committed vectors in the batched vector.
+    pub batching_coeffs: Vec<F>,
+
+    /// The multilinear extension of the batched vector evaluated in `point`.
+    pub value: F,
+}
+
+impl<F, M> Config<F, M>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// Prove that the committed `vectors` are close to the Reed-Solomon code.
+    ///
+    /// Arguments are as in [`Self::prove`], without linear forms. Should be
+    /// used with a configuration from [`Config::new_proximity`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn prove_proximity<'a, H, R>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        vectors: Vec<Cow<'a, [M::Source]>>,
+        witnesses: Vec<Cow<'a, Witness<F, M>>>,
+    ) -> FinalClaim<F>
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        let (claim, deferred) = self.prove_inner(
+            NttContext::global(),
+            prover_state,
+            vectors,
+            witnesses,
+            Vec::new(),
+            Cow::Owned(Vec::new()),
+        );
+        debug_assert!(deferred.is_empty());
+        claim
+    }
+
+    /// Verify that the committed vectors are close to the Reed-Solomon code.
+    ///
+    /// On success the committed vectors are [`Config::proximity`]-close to the
+    /// code and the returned [`FinalClaim`] holds for their closest codewords.
+    pub fn verify_proximity<H>(
+        &self,
+        verifier_state: &mut VerifierState<'_, H>,
+        commitments: &[&Commitment<F>],
+    ) -> VerificationResult<FinalClaim<F>>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        let (claim, _deferred) = self.verify_inner(verifier_state, commitments, &[], &[])?;
+        Ok(claim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use ark_ff::{Field, UniformRand};
+
+    use super::super::Config;
+    use crate::{
+        algebra::{
+            fields::Field64,
+            linear_form::{Evaluate, MultilinearExtension},
+        },
+        hash,
+        parameters::{FoldingFactor, MultivariateParameters, ProtocolParameters, SoundnessType},
+        transcript::{codecs::Empty, DomainSeparator, ProverState, VerifierState},
+    };
+
+    /// Field type used in the tests.
+    type F = Field64;
+
+    fn run_proximity(num_variables: usize, batch_size: usize, soundness_type: SoundnessType) {
+        let mut rng = ark_std::test_rng();
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type,
+            starting_log_inv_rate: 1,
+            batch_size,
+            hash_id: hash::SHA2,
+        };
+        let params =
+            Config::new_proximity(MultivariateParameters::new(num_variables), &whir_params);
+        assert_eq!(params.initial_committer.out_domain_samples, 0);
+        assert!(params.proximity() > 0.0 && params.proximity() < 1.0);
+
+        let vectors: Vec<Vec<F>> = (0..batch_size)
+            .map(|_| (0..1 << num_variables).map(|_| F::rand(&mut rng)).collect())
+            .collect();
+        let vec_refs = vectors.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &vec_refs);
+        let prover_claim = params.prove_proximity(
+            &mut prover_state,
+            vec_refs.iter().map(|v| Cow::Borrowed(*v)).collect(),
+            vec![Cow::Owned(witness)],
+        );
+
+        // The final claim is the batched multilinear extension in the folding point.
+        assert_eq!(prover_claim.point.0.len(), num_variables);
+        assert_eq!(prover_claim.batching_coeffs.len(), batch_size);
+        assert_eq!(prover_claim.batching_coeffs[0], F::ONE);
+        let form = MultilinearExtension::new(prover_claim.point.0.clone());
+        let expected = vec_refs
+            .iter()
+            .zip(&prover_claim.batching_coeffs)
+            .map(|(vector, coeff)| *coeff * form.evaluate(params.embedding(), vector))
+            .sum::<F>();
+        assert_eq!(prover_claim.value, expected);
+
+        let proof = prover_state.proof();
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        let verifier_claim = params
+            .verify_proximity(&mut verifier_state, &[&commitment])
+            .unwrap();
+        assert_eq!(verifier_claim, prover_claim);
+    }
+
+    #[test]
+    fn test_proximity() {
+        for soundness_type in [
+            SoundnessType::ConjectureList,
+            SoundnessType::ProvableList,
+            SoundnessType::UniqueDecoding,
+        ] {
+            for num_variables in [2, 5, 6] {
+                for batch_size in [1, 3] {
+                    run_proximity(num_variables, batch_size, soundness_type);
+                }
+            }
+        }
+    }
+}
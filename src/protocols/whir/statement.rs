@@ -0,0 +1,342 @@
+//! Serializable WHIR statements.
+
+use std::fmt;
+
+use ark_ff::Field;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    algebra::linear_form::{LinearForm, SerializableLinearForm, LINEAR_FORMS},
+    engines::EngineId,
+};
+
+/// Linear forms and the claimed evaluations of the committed vectors on them.
+///
+/// The `evaluations` are in the row-major layout of [`super::Config::prove`]. Linear forms
+/// are serialized by kind and deserialized through [`LINEAR_FORMS`], so a statement can be
+/// shipped to a separate verifier together with the proof.
+pub struct Statement<F: Field> {
+    pub linear_forms: Vec<Box<dyn SerializableLinearForm<F>>>,
+    pub evaluations: Vec<F>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+struct SerializedLinearForm {
+    kind: EngineId,
+    #[serde(with = "crate::ark_serde::bytes")]
+    parameters: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "F: Field")]
+struct SerializedStatement<F: Field> {
+    linear_forms: Vec<SerializedLinearForm>,
+    #[serde(with = "crate::ark_serde::canonical")]
+    evaluations: Vec<F>,
+}
+
+impl<F: Field> Statement<F> {
+    pub const fn new(
+        linear_forms: Vec<Box<dyn SerializableLinearForm<F>>>,
+        evaluations: Vec<F>,
+    ) -> Self {
+        Self {
+            linear_forms,
+            evaluations,
+        }
+    }
+
+    /// The linear forms as taken by [`super::Config::verify`].
+    pub fn linear_form_refs(&self) -> Vec<&dyn LinearForm<F>> {
+        self.linear_forms
+            .iter()
+            .map(|form| form.as_ref() as &dyn LinearForm<F>)
+            .collect()
+    }
+
+    /// The linear forms and evaluations as taken by [`super::Config::prove`].
+    pub fn into_parts(self) -> (Vec<Box<dyn LinearForm<F>>>, Vec<F>) {
+        let linear_forms = self
+            .linear_forms
+            .into_iter()
+            .map(|form| form as Box<dyn LinearForm<F>>)
+            .collect();
+        (linear_forms, self.evaluations)
+    }
+
+    fn serialized_forms(&self) -> Vec<SerializedLinearForm> {
+        self.linear_forms
+            .iter()
+            .map(|form| SerializedLinearForm {
+                kind: form.kind(),
+                parameters: form.serialize_parameters(),
+            })
+            .collect()
+    }
+}
+
+impl<F: Field> PartialEq for Statement<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.evaluations == other.evaluations && self.serialized_forms() == other.serialized_forms()
+    }
+}
+
+impl<F: Field> fmt::Debug for Statement<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Statement")
+            .field("linear_forms", &self.serialized_forms())
+            .field("evaluations", &self.evaluations)
+            .finish()
+    }
+}
+
+impl<F: Field> Serialize for Statement<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedStatement {
+            linear_forms: self.serialized_forms(),
+            evaluations: self.evaluations.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for Statement<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let statement = SerializedStatement::<F>::deserialize(deserializer)?;
+        let linear_forms = statement
+            .linear_forms
+            .into_iter()
+            .map(|form| {
+                LINEAR_FORMS
+                    .deserialize(form.kind, &form.parameters)
+                    .ok_or_else(|| D::Error::custom(format!("Invalid linear form {}", form.kind)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(linear_forms, statement.evaluations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::UniformRand;
+
+    use super::*;
+    use crate::{
+        algebra::{
+            embedding::Basefield,
+            fields::{Field64, Field64_2},
+            linear_form::{
+                kind_id, Covector, Evaluate, LinearFormKind, MultilinearExtension, Shifted,
+                SparseCovector, TensorProduct, UnivariateEvaluation,
+            },
+        },
+        hash,
+        parameters::{FoldingFactor, MultivariateParameters, ProtocolParameters, SoundnessType},
+        protocols::whir::Config,
+        transcript::{codecs::Empty, DomainSeparator, ProverState, VerifierState},
+        utils::test_serde,
+    };
+
+    /// Field type used in the tests.
+    type F = Field64;
+
+    /// Extension field type used in the tests.
+    type EF = Field64_2;
+
+    const SHIFTED_MLE: &str = "test::shifted_multilinear_extension";
+
+    /// A user-defined serializable linear form.
+    impl SerializableLinearForm<EF> for Shifted<MultilinearExtension<EF>> {
+        fn kind(&self) -> EngineId {
+            kind_id(SHIFTED_MLE)
+        }
+
+        fn serialize_parameters(&self) -> Vec<u8> {
+            self.inner.serialize_parameters()
+        }
+    }
+
+    struct ShiftedMleKind;
+
+    impl LinearFormKind<EF> for ShiftedMleKind {
+        fn name(&self) -> Cow<'_, str> {
+            SHIFTED_MLE.into()
+        }
+
+        fn deserialize_parameters(
+            &self,
+            mut parameters: &[u8],
+        ) -> Option<Box<dyn SerializableLinearForm<EF>>> {
+            let point = Vec::<EF>::deserialize_compressed(&mut parameters).ok()?;
+            parameters.is_empty().then(|| {
+                Box::new(Shifted::new(MultilinearExtension::new(point)))
+                    as Box<dyn SerializableLinearForm<EF>>
+            })
+        }
+    }
+
+    fn whir_config(num_variables: usize) -> Config<EF> {
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size: 1,
+            hash_id: hash::SHA2,
+        };
+        Config::new(MultivariateParameters::new(num_variables), &whir_params)
+    }
+
+    #[test]
+    fn test_statement() {
+        let num_variables = 4;
+        let size = 1 << num_variables;
+        let mut rng = ark_std::test_rng();
+
+        LINEAR_FORMS.register::<EF>(Arc::new(ShiftedMleKind));
+        assert!(LINEAR_FORMS.contains::<EF>(kind_id(SHIFTED_MLE)));
+        assert!(!LINEAR_FORMS.contains::<F>(kind_id(SHIFTED_MLE)));
+        assert!(LINEAR_FORMS.contains::<F>(kind_id("covector")));
+
+        let params = whir_config(num_variables);
+        let vector = (0..size).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+
+        let linear_forms: Vec<Box<dyn SerializableLinearForm<EF>>> = vec![
+            Box::new(MultilinearExtension::new(
+                (0..num_variables).map(|_| EF::rand(&mut rng)).collect(),
+            )),
+            Box::new(UnivariateEvaluation::new(EF::rand(&mut rng), size)),
+            Box::new(Covector {
+                deferred: false,
+                vector: (0..size).map(|_| EF::rand(&mut rng)).collect(),
+            }),
+            Box::new(SparseCovector::new(size, vec![(3, EF::rand(&mut rng))])),
+            Box::new(TensorProduct::new(vec![
+                (0..4).map(|_| EF::rand(&mut rng)).collect(),
+                (0..4).map(|_| EF::rand(&mut rng)).collect(),
+            ])),
+            Box::new(Shifted::new(MultilinearExtension::new(
+                (0..num_variables).map(|_| EF::rand(&mut rng)).collect(),
+            ))),
+        ];
+        let embedding = Basefield::<EF>::new();
+        let evaluations = linear_forms
+            .iter()
+            .map(|form| Covector::from(&**form).evaluate(&embedding, &vector))
+            .collect();
+        let statement = Statement::new(linear_forms, evaluations);
+        test_serde(&statement);
+
+        // Prove, then verify with a statement that went through serialization.
+        let json = serde_json::to_string(&statement).unwrap();
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &[&vector]);
+        let (linear_forms, evaluations) = statement.into_parts();
+        params.prove(
+            &mut prover_state,
+            vec![Cow::Borrowed(vector.as_slice())],
+            vec![Cow::Owned(witness)],
+            linear_forms,
+            Cow::Owned(evaluations),
+        );
+        let proof = prover_state.proof();
+
+        let statement: Statement<EF> = serde_json::from_str(&json).unwrap();
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        let linear_form_refs = statement.linear_form_refs();
+        params
+            .verify(
+                &mut verifier_state,
+                &[&commitment],
+                &linear_form_refs,
+                &statement.evaluations,
+            )
+            .unwrap()
+            .check_with(&linear_form_refs)
+            .unwrap();
+    }
+
+    /// A linear form of the wrong size is rejected by the verifier.
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_statement_wrong_size() {
+        let num_variables = 4;
+        let mut rng = ark_std::test_rng();
+        let params = whir_config(num_variables);
+        let vector = (0..1 << num_variables)
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let linear_form =
+            MultilinearExtension::new((0..num_variables).map(|_| EF::rand(&mut rng)).collect());
+        let evaluation = linear_form.evaluate(&Basefield::<EF>::new(), &vector);
+
+        let ds = DomainSeparator::protocol(&params)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = params.commit(&mut prover_state, &[&vector]);
+        params.prove(
+            &mut prover_state,
+            vec![Cow::Borrowed(vector.as_slice())],
+            vec![Cow::Owned(witness)],
+            vec![Box::new(linear_form)],
+            Cow::Owned(vec![evaluation]),
+        );
+        let proof = prover_state.proof();
+
+        let oversized =
+            MultilinearExtension::new((0..=num_variables).map(|_| EF::rand(&mut rng)).collect());
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = params.receive_commitment(&mut verifier_state).unwrap();
+        assert!(params
+            .verify(
+                &mut verifier_state,
+                &[&commitment],
+                &[&oversized],
+                &[evaluation],
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_linear_forms() {
+        fn deserialize(kind: &str, parameters: &impl CanonicalSerialize) -> bool {
+            let mut bytes = Vec::new();
+            parameters.serialize_compressed(&mut bytes).unwrap();
+            LINEAR_FORMS
+                .deserialize::<EF>(kind_id(kind), &bytes)
+                .is_some()
+        }
+        let one = EF::ONE;
+
+        assert!(deserialize("multilinear_extension", &vec![one; 63]));
+        assert!(!deserialize("multilinear_extension", &vec![one; 64]));
+        assert!(deserialize(
+            "sparse_covector",
+            &(8_usize, false, vec![(7_usize, one)])
+        ));
+        assert!(!deserialize(
+            "sparse_covector",
+            &(8_usize, false, vec![(8_usize, one)])
+        ));
+        assert!(deserialize(
+            "sparse_covector",
+            &(6_usize, false, vec![(3_usize, one)])
+        ));
+        assert!(deserialize("univariate_evaluation", &(8_usize, one)));
+        assert!(!deserialize("univariate_evaluation", &(0_usize, one)));
+        assert!(!deserialize("univariate_evaluation", &(6_usize, one)));
+        assert!(deserialize("tensor_product", &vec![vec![one; 2]; 63]));
+        assert!(!deserialize("tensor_product", &vec![vec![one; 2]; 64]));
+        assert!(!deserialize("tensor_product", &vec![vec![one; 3]]));
+    }
+}
@@ -1,6 +1,6 @@
 use ark_ff::FftField;
 
-use super::{config::Config, Commitment};
+use super::{config::Config, Commitment, DeferredClaims, FinalClaim};
 use crate::{
     algebra::{
         dot,
@@ -41,15 +41,41 @@ where
     /// and reconstructs the combined constraints using RLC. Round 0 verifies openings in all
     /// N original commitment trees, while subsequent rounds verify the single batched vector.
     ///
-    /// Returns the constraint evaluation point and values of deferred constraints.
-    #[allow(clippy::too_many_lines)]
+    /// Returns the claimed evaluations of the deferred linear forms, which the caller has to
+    /// discharge for the proof to be sound.
     pub fn verify<H>(
         &self,
         verifier_state: &mut VerifierState<'_, H>,
         commitments: &[&Commitment<F>],
         linear_forms: &[&dyn LinearForm<F>],
         evaluations: &[F],
-    ) -> VerificationResult<(MultilinearPoint<F>, Vec<F>)>
+    ) -> VerificationResult<DeferredClaims<F>>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+    {
+        let (claim, deferred) =
+            self.verify_inner(verifier_state, commitments, linear_forms, evaluations)?;
+        Ok(DeferredClaims::new(
+            claim.point,
+            self.initial_sumcheck.univariate_skip,
+            deferred,
+        ))
+    }
+
+    /// Implementation of [`Self::verify`] that also returns the final folded claim.
+    #[allow(clippy::too_many_lines)]
+    pub(super) fn verify_inner<H>(
+        &self,
+        verifier_state: &mut VerifierState<'_, H>,
+        commitments: &[&Commitment<F>],
+        linear_forms: &[&dyn LinearForm<F>],
+        evaluations: &[F],
+    ) -> VerificationResult<(FinalClaim<F>, Vec<F>)>
     where
         H: DuplexSpongeInterface,
         F: Codec<[H::U]>,
@@ -60,8 +86,11 @@ where
     {
         let num_vectors = commitments.len() * self.initial_committer.num_vectors;
         verify!(linear_forms.len() * num_vectors == evaluations.len());
+        for linear_form in linear_forms {
+            verify!(linear_form.size() == self.initial_size());
+        }
         if num_vectors == 0 {
-            return Ok((MultilinearPoint::default(), Vec::new()));
+            return Ok((FinalClaim::default(), Vec::new()));
         }
 
         // Complete the constraint and evaluation matrix with OODs and their cross-terms.
@@ -73,7 +102,9 @@ where
             let mut vector_offset = 0;
             for commitment in commitments {
                 for (weights, oods_row) in zip_strict(
-                    commitment.out_of_domain().evaluators(self.initial_size()),
+                    commitment
+                        .out_of_domain()
+                        .evaluators(self.initial_size(), self.initial_committer.basis),
                     commitment.out_of_domain().rows(),
                 ) {
                     for j in 0..num_vectors {
@@ -96,6 +127,26 @@ where
             commitments,
             batching_weights: vector_rlc_coeffs.clone(),
         };
+        let batching_coeffs = vector_rlc_coeffs.clone();
+
+        // Evaluations of the batched vector at each linear form.
+        let evaluations = evaluations
+            .chunks_exact(num_vectors)
+            .map(|row| dot(&vector_rlc_coeffs, row))
+            .collect::<Vec<F>>();
+
+        // Reduce evaluations at several points to a single point.
+        let reduced;
+        let reduced_forms: [&dyn LinearForm<F>; 1];
+        let (linear_forms, evaluations) =
+            match self.evaluation_points(linear_forms, oods_evals.len()) {
+                Some(points) => {
+                    reduced = self.verify_evaluations(verifier_state, &points, &evaluations)?;
+                    reduced_forms = [&reduced.0];
+                    (&reduced_forms[..], vec![reduced.1])
+                }
+                None => (linear_forms, evaluations),
+            };
 
         // Random linear combination of the constraints.
         let constraint_rlc_coeffs: Vec<F> =
@@ -104,12 +155,7 @@ where
         let oods_rlc_coeffs = constraint_rlc_coeffs[..oods_evals.len()].to_vec();
 
         // Compute "The Sum"
-        let mut the_sum = zip_strict(
-            &initial_form_rlc_coeffs,
-            evaluations.chunks_exact(num_vectors),
-        )
-        .map(|(poly_coeff, row)| *poly_coeff * dot(&vector_rlc_coeffs, row))
-        .sum::<F>();
+        let mut the_sum = dot(&initial_form_rlc_coeffs, &evaluations);
         the_sum += zip_strict(&oods_rlc_coeffs, oods_matrix.chunks_exact(num_vectors))
             .map(|(poly_coeff, row)| *poly_coeff * dot(&vector_rlc_coeffs, row))
             .sum::<F>();
@@ -123,12 +169,13 @@ where
             // (If we did run it, all sumcheck polynomials would be constant zero)
             assert_eq!(the_sum, F::ZERO);
             let folding_randomness =
-                verifier_state.verifier_message_vec(self.initial_sumcheck.num_rounds);
+                verifier_state.verifier_message_vec(self.initial_sumcheck.num_challenges());
             self.initial_sumcheck.round_pow.verify(verifier_state)?;
             MultilinearPoint(folding_randomness)
         } else {
             self.initial_sumcheck.verify(verifier_state, &mut the_sum)?
         };
+        let mut folding_weights = self.initial_sumcheck.folding_weights(&folding_randomness);
         round_folding_randomness.push(folding_randomness);
 
         for (round_index, round_config) in self.round_configs.iter().enumerate() {
@@ -141,7 +188,7 @@ where
             round_config.pow.verify(verifier_state)?;
 
             // Open the previous round's commitment, producing in-domain evaluations.
-            let (in_domain, poly_rlc) = match prev_commitment {
+            let (in_domain, poly_rlc, basis) = match prev_commitment {
                 RoundCommitment::Initial {
                     commitments,
                     batching_weights,
@@ -149,30 +196,38 @@ where
                     let in_domain = self.initial_committer.verify(verifier_state, commitments)?;
                     // TODO: Skip lift and keep initial in-domain in subfield for evaluation.
                     // This should be every so slightly more performant.
-                    (in_domain.lift(self.embedding()), batching_weights)
+                    (
+                        in_domain.lift(self.embedding()),
+                        batching_weights,
+                        self.initial_committer.basis,
+                    )
                 }
                 RoundCommitment::Round { commitment } => {
                     let prev_round_config = &self.round_configs[round_index - 1];
                     let in_domain = prev_round_config
                         .irs_committer
                         .verify(verifier_state, &[&commitment])?;
-                    (in_domain, vec![F::ONE])
+                    (
+                        in_domain,
+                        vec![F::ONE],
+                        prev_round_config.irs_committer.basis,
+                    )
                 }
             };
 
             // Random linear combination of out- and in-domain constraints
             let constraint_weights = commitment
                 .out_of_domain()
-                .evaluators(round_config.initial_size())
-                .chain(in_domain.evaluators(round_config.initial_size()))
+                .evaluators(
+                    round_config.initial_size(),
+                    round_config.irs_committer.basis,
+                )
+                .chain(in_domain.evaluators(round_config.initial_size(), basis))
                 .collect::<Vec<_>>();
             let constraint_values = commitment
                 .out_of_domain()
                 .values(&[F::ONE])
-                .chain(in_domain.values(&tensor_product(
-                    &poly_rlc,
-                    &round_folding_randomness.last().unwrap().eq_weights(),
-                )))
+                .chain(in_domain.values(&tensor_product(&poly_rlc, &folding_weights)))
                 .collect::<Vec<_>>();
             let constraint_rlc_coeffs =
                 geometric_challenge(verifier_state, constraint_values.len());
@@ -181,6 +236,7 @@ where
 
             // Sumcheck round
             let folding_randomness = round_config.sumcheck.verify(verifier_state, &mut the_sum)?;
+            folding_weights = round_config.sumcheck.folding_weights(&folding_randomness);
             round_folding_randomness.push(folding_randomness);
 
             prev_commitment = RoundCommitment::Round { commitment };
@@ -193,30 +249,35 @@ where
         self.final_pow.verify(verifier_state)?;
 
         // Open previous witness, as usual
-        let (in_domain, poly_rlc) = match prev_commitment {
+        let (in_domain, poly_rlc, basis) = match prev_commitment {
             RoundCommitment::Initial {
                 commitments,
                 batching_weights,
             } => {
                 let in_domain = self.initial_committer.verify(verifier_state, commitments)?;
-                (in_domain.lift(self.embedding()), batching_weights)
+                (
+                    in_domain.lift(self.embedding()),
+                    batching_weights,
+                    self.initial_committer.basis,
+                )
             }
             RoundCommitment::Round { commitment } => {
                 let prev_round_config = &self.round_configs.last().unwrap();
                 let in_domain = prev_round_config
                     .irs_committer
                     .verify(verifier_state, &[&commitment])?;
-                (in_domain, vec![F::ONE])
+                (
+                    in_domain,
+                    vec![F::ONE],
+                    prev_round_config.irs_committer.basis,
+                )
             }
         };
 
         // Verify in-domain constraints directly
         for (weights, evals) in zip_strict(
-            in_domain.evaluators(final_vector.len()),
-            in_domain.values(&tensor_product(
-                &poly_rlc,
-                &round_folding_randomness.last().unwrap().eq_weights(),
-            )),
+            in_domain.evaluators(final_vector.len(), basis),
+            in_domain.values(&tensor_product(&poly_rlc, &folding_weights)),
         ) {
             verify!(weights.evaluate(&Identity::<F>::new(), &final_vector) == evals);
         }
@@ -241,8 +302,15 @@ where
                 |p| self.round_configs[p].initial_num_variables(),
             );
             let start = folding_randomness.0.len().saturating_sub(num_variables);
+            let point = &folding_randomness.0[start..];
             for (rlc_coeff, weights) in zip_strict(weights_rlc_coeffs, weights) {
-                weight_eval += rlc_coeff * weights.mle_evaluate(&folding_randomness.0[start..]);
+                // The initial constraints are folded by the univariate skip, if any.
+                weight_eval += rlc_coeff
+                    * if round == 0 {
+                        self.initial_sumcheck.mle_evaluate(&weights, point)
+                    } else {
+                        weights.mle_evaluate(point)
+                    };
             }
         }
 
@@ -255,7 +323,8 @@ where
                 verify!(deferred.is_some());
                 deferred.unwrap()
             } else {
-                weights.mle_evaluate(&folding_randomness.0)
+                self.initial_sumcheck
+                    .mle_evaluate(*weights, &folding_randomness.0)
             };
             weight_eval += rlc_coeff * eval;
         }
@@ -266,7 +335,12 @@ where
             .evaluate(&Identity::new(), &final_vector);
         verify!(poly_eval * weight_eval == the_sum);
 
-        // Return the evaluation point and the claimed values of the deferred weights.
-        Ok((folding_randomness, deferred))
+        // Return the final claim and the claimed values of the deferred weights.
+        let claim = FinalClaim {
+            point: folding_randomness,
+            batching_coeffs,
+            value: poly_eval,
+        };
+        Ok((claim, deferred))
     }
 }
@@ -0,0 +1,313 @@
+//! Zerocheck for constraints on committed vectors.
+//!
+//! Proves that a constraint `C(v_1[i], …, v_k[i])` vanishes for every index `i` of the
+//! committed vectors `v_j`. The verifier samples a random point `r` and the claim becomes
+//! `∑_i eq(r, i) · C(v_1[i], …, v_k[i]) == 0`, which a [`composite`] sumcheck reduces to
+//! evaluations of the `v_j` at a single point. These are then proven with WHIR.
+
+use std::{borrow::Cow, fmt};
+
+use ark_ff::FftField;
+use ark_std::rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    algebra::{
+        embedding::{Basefield, Embedding},
+        fields::FieldWithSize,
+        linear_form::{LinearForm, MultilinearExtension},
+        sumcheck::ProductCovector,
+        MultilinearPoint,
+    },
+    bits::Bits,
+    hash::Hash,
+    protocols::{
+        proof_of_work,
+        sumcheck::composite,
+        whir::{self, Commitment, Witness},
+    },
+    transcript::{
+        codecs::U64, Codec, Decoding, DuplexSpongeInterface, ProverMessage, ProverState,
+        VerificationResult, VerifierMessage, VerifierState,
+    },
+    type_info::Type,
+    verify,
+};
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[serde(bound = "F: FftField, M: Embedding<Target = F>, M::Source: FftField")]
+pub struct Config<F, M = Basefield<F>>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// Proof of work before the random point `r`, which has error `n / |F|`.
+    pub point_pow: proof_of_work::Config,
+    /// Sumcheck of the eq-weighted constraint, of degree one more than the constraint.
+    pub sumcheck: composite::Config<F>,
+    pub whir: whir::Config<F, M>,
+}
+
+impl<F, M> Config<F, M>
+where
+    F: FftField,
+    M: Embedding<Target = F>,
+    M::Source: FftField,
+{
+    /// Zerocheck for constraints of total degree at most `degree` on vectors committed
+    /// with `whir`, which must support an initial statement.
+    pub fn new(whir: whir::Config<F, M>, degree: usize) -> Self {
+        assert!(
+            whir.initial_statement,
+            "Zerocheck requires a WHIR configuration with an initial statement"
+        );
+        let num_variables = whir.initial_num_variables();
+        let field_size_bits = F::field_size_in_bits() as f64;
+        let pow = |error: f64| proof_of_work::Config {
+            hash_id: whir.initial_sumcheck.round_pow.hash_id,
+            threshold: proof_of_work::threshold(Bits::new(
+                (whir.security_level as f64 - error).max(0.0),
+            )),
+        };
+
+        // A non-vanishing constraint sums to zero at a random point r with probability
+        // n / |F|, and each sumcheck round has error (degree + 1) / |F|.
+        let point_pow = pow(field_size_bits - (num_variables as f64).log2());
+        let sumcheck = composite::Config {
+            field: Type::new(),
+            initial_size: whir.initial_size(),
+            degree: degree + 1,
+            round_pow: pow(field_size_bits - ((degree + 1) as f64).log2()),
+            num_rounds: num_variables,
+        };
+        Self {
+            point_pow,
+            sumcheck,
+            whir,
+        }
+    }
+
+    /// The degree of the constraints.
+    pub const fn degree(&self) -> usize {
+        self.sumcheck.degree - 1
+    }
+
+    /// Prove that `constraint` vanishes on all entries of the committed `vectors`.
+    ///
+    /// The `vectors` and `witnesses` are as in [`whir::Config::prove`]. The `constraint`
+    /// receives one value per vector and must have total degree at most [`Self::degree`].
+    ///
+    /// Returns the point at which the vectors were evaluated and their evaluations.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn prove<'a, H, R, C>(
+        &self,
+        prover_state: &mut ProverState<H, R>,
+        vectors: Vec<Cow<'a, [M::Source]>>,
+        witnesses: Vec<Cow<'a, Witness<F, M>>>,
+        constraint: &C,
+    ) -> (MultilinearPoint<F>, Vec<F>)
+    where
+        H: DuplexSpongeInterface,
+        R: RngCore + CryptoRng,
+        F: Codec<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+        C: Fn(&[F]) -> F + Sync,
+    {
+        // Randomize the constraint over the hypercube
+        self.point_pow.prove(prover_state);
+        let point: Vec<F> = prover_state.verifier_message_vec(self.sumcheck.num_rounds);
+
+        // Reduce to evaluations of the vectors at a single point
+        let inputs = vectors.iter().map(|vector| &vector[..]).collect::<Vec<_>>();
+        let mut sum = F::ZERO;
+        let (folding_randomness, folded) = self.sumcheck.prove_product_mixed(
+            prover_state,
+            self.whir.embedding(),
+            ProductCovector::eq(&point, F::ONE),
+            &inputs,
+            constraint,
+            &mut sum,
+        );
+        let evaluations = folded.iter().map(|input| input[0]).collect::<Vec<_>>();
+        for evaluation in &evaluations {
+            prover_state.prover_message(evaluation);
+        }
+
+        // Prove the evaluations
+        let linear_form = MultilinearExtension::new(folding_randomness.0.clone());
+        self.whir.prove(
+            prover_state,
+            vectors,
+            witnesses,
+            vec![Box::new(linear_form)],
+            Cow::Owned(evaluations.clone()),
+        );
+        (folding_randomness, evaluations)
+    }
+
+    /// Verify that `constraint` vanishes on all entries of the committed vectors.
+    ///
+    /// Returns the point at which the vectors were evaluated and their evaluations.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub fn verify<H, C>(
+        &self,
+        verifier_state: &mut VerifierState<'_, H>,
+        commitments: &[&Commitment<F>],
+        constraint: &C,
+    ) -> VerificationResult<(MultilinearPoint<F>, Vec<F>)>
+    where
+        H: DuplexSpongeInterface,
+        F: Codec<[H::U]>,
+        u8: Decoding<[H::U]>,
+        [u8; 32]: Decoding<[H::U]>,
+        U64: Codec<[H::U]>,
+        Hash: ProverMessage<[H::U]>,
+        C: Fn(&[F]) -> F,
+    {
+        let num_vectors = commitments.len() * self.whir.initial_committer.num_vectors;
+
+        // Randomize the constraint over the hypercube
+        self.point_pow.verify(verifier_state)?;
+        let point: Vec<F> = verifier_state.verifier_message_vec(self.sumcheck.num_rounds);
+
+        // Reduce to evaluations of the vectors at a single point
+        let mut sum = F::ZERO;
+        let folding_randomness = self.sumcheck.verify(verifier_state, &mut sum)?;
+        let evaluations: Vec<F> = verifier_state.prover_messages_vec(num_vectors)?;
+        let eq = MultilinearExtension::new(point).mle_evaluate(&folding_randomness.0);
+        verify!(sum == eq * constraint(&evaluations));
+
+        // Verify the evaluations
+        let linear_form = MultilinearExtension::new(folding_randomness.0.clone());
+        let linear_forms: [&dyn LinearForm<F>; 1] = [&linear_form];
+        self.whir
+            .verify(verifier_state, commitments, &linear_forms, &evaluations)?
+            .check_with(&linear_forms)?;
+        Ok((folding_randomness, evaluations))
+    }
+}
+
+impl<F: FftField> fmt::Display for Config<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Zerocheck:\n  point pow {:.2}\n  sumcheck {}",
+            self.point_pow.difficulty(),
+            self.sumcheck
+        )?;
+        write!(f, "{}", self.whir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, UniformRand};
+
+    use super::*;
+    use crate::{
+        algebra::fields::{Field64, Field64_2},
+        parameters::{FoldingFactor, MultivariateParameters, ProtocolParameters, SoundnessType},
+        transcript::{codecs::Empty, DomainSeparator},
+        utils::test_serde,
+    };
+
+    type F = Field64;
+    type EF = Field64_2;
+
+    fn config(num_variables: usize, batch_size: usize, degree: usize) -> Config<EF> {
+        let whir_params = ProtocolParameters {
+            initial_statement: true,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size,
+            hash_id: crate::hash::SHA2,
+        };
+        let whir = whir::Config::new(MultivariateParameters::new(num_variables), &whir_params);
+        Config::new(whir, degree)
+    }
+
+    /// Proves `a · b - c == 0` for `c = a · b` and verifies it with `constraint`.
+    fn run_product(
+        num_variables: usize,
+        constraint: impl Fn(&[EF]) -> EF,
+    ) -> VerificationResult<()> {
+        let mut rng = ark_std::test_rng();
+        let config = config(num_variables, 3, 2);
+        test_serde(&config);
+        let a = (0..1 << num_variables)
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let b = (0..1 << num_variables)
+            .map(|_| F::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let c = a.iter().zip(&b).map(|(a, b)| *a * b).collect::<Vec<_>>();
+
+        let ds = DomainSeparator::protocol(&config)
+            .session(&format!("Test at {}:{}", file!(), line!()))
+            .instance(&Empty);
+        let mut prover_state = ProverState::new_std(&ds);
+        let witness = config.whir.commit(
+            &mut prover_state,
+            &[a.as_slice(), b.as_slice(), c.as_slice()],
+        );
+        let (point, evaluations) = config.prove(
+            &mut prover_state,
+            vec![
+                Cow::Borrowed(a.as_slice()),
+                Cow::Borrowed(b.as_slice()),
+                Cow::Borrowed(c.as_slice()),
+            ],
+            vec![Cow::Owned(witness)],
+            &|values: &[EF]| values[0] * values[1] - values[2],
+        );
+        let proof = prover_state.proof();
+
+        let mut verifier_state = VerifierState::new_std(&ds, &proof);
+        let commitment = config.whir.receive_commitment(&mut verifier_state)?;
+        let (verifier_point, verifier_evaluations) =
+            config.verify(&mut verifier_state, &[&commitment], &constraint)?;
+        assert_eq!(verifier_point, point);
+        assert_eq!(verifier_evaluations, evaluations);
+        verifier_state.check_eof()
+    }
+
+    #[test]
+    fn test_product() {
+        for num_variables in [2, 4, 5] {
+            run_product(num_variables, |values| values[0] * values[1] - values[2]).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "verifier_panics", should_panic)]
+    fn test_wrong_constraint() {
+        let result = run_product(4, |values| values[0] * values[1] - values[2] + EF::ONE);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "initial statement")]
+    fn test_without_initial_statement() {
+        let whir_params = ProtocolParameters {
+            initial_statement: false,
+            security_level: 32,
+            pow_bits: 0,
+            folding_factor: FoldingFactor::Constant(2),
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            batch_size: 1,
+            hash_id: crate::hash::SHA2,
+        };
+        let whir = whir::Config::new(MultivariateParameters::new(4), &whir_params);
+        let _ = Config::new(whir, 2);
+    }
+}
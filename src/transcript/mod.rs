@@ -5,10 +5,11 @@
 
 pub mod codecs;
 mod mock_sponge;
+mod stream;
 
 #[cfg(debug_assertions)]
 use std::any::type_name;
-use std::fmt::Debug;
+use std::{fmt::Debug, io::Read};
 
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::rand::{rngs::StdRng, CryptoRng, RngCore};
@@ -22,6 +23,8 @@ pub use spongefish::{
 
 #[cfg(test)]
 pub use self::mock_sponge::MockSponge;
+use self::stream::ByteStream;
+pub use self::stream::StreamLimits;
 
 #[macro_export]
 macro_rules! verify {
@@ -86,10 +89,23 @@ where
     H: DuplexSpongeInterface,
 {
     inner: spongefish::VerifierState<'a, H>,
-    hints: &'a [u8],
+    source: Source<'a>,
 
+    /// Expected interaction pattern, if known.
     #[cfg(debug_assertions)]
-    pattern: &'a [Interaction],
+    pattern: Option<&'a [Interaction]>,
+}
+
+/// Where a [`VerifierState`] reads prover messages and hints from.
+enum Source<'a> {
+    /// A fully loaded [`Proof`]. The NARG string is owned by the spongefish state.
+    Proof { hints: &'a [u8] },
+
+    /// Incrementally read byte streams.
+    Stream {
+        narg_string: ByteStream<'a>,
+        hints: ByteStream<'a>,
+    },
 }
 
 pub trait VerifierMessage {
@@ -265,9 +281,49 @@ where
                 .session(ds.session_id)
                 .instance(ds.instance)
                 .to_verifier(duplex, &proof.narg_string),
-            hints: &proof.hints,
+            source: Source::Proof {
+                hints: &proof.hints,
+            },
+            #[cfg(debug_assertions)]
+            pattern: Some(proof.pattern.as_slice()),
+        }
+    }
+
+    /// Construct a verifier state that reads the proof incrementally.
+    ///
+    /// `narg_string` and `hints` must produce the bytes of [`Proof::narg_string`] and
+    /// [`Proof::hints`] respectively. At most `limits.buffer_size` bytes of each are held
+    /// in memory at any time, and the proof is rejected as soon as a source fails, runs
+    /// short, or exceeds its byte limit.
+    ///
+    /// **Note.** Prover messages are read by this wrapper, so they must not be read
+    /// through [`Self::as_spongefish`].
+    pub fn from_reader<I>(
+        ds: &DomainSeparator<'_, I>,
+        narg_string: impl Read + 'a,
+        hints: impl Read + 'a,
+        limits: StreamLimits,
+        duplex: H,
+    ) -> Self
+    where
+        u8: Encoding<[H::U]>,
+        I: Encoding<[H::U]>,
+    {
+        Self {
+            inner: spongefish::DomainSeparator::new(ds.protocol_id)
+                .session(ds.session_id)
+                .instance(ds.instance)
+                .to_verifier(duplex, &[]),
+            source: Source::Stream {
+                narg_string: ByteStream::new(
+                    narg_string,
+                    limits.buffer_size,
+                    limits.max_narg_bytes,
+                ),
+                hints: ByteStream::new(hints, limits.buffer_size, limits.max_hint_bytes),
+            },
             #[cfg(debug_assertions)]
-            pattern: &proof.pattern,
+            pattern: None,
         }
     }
 
@@ -278,9 +334,18 @@ where
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn check_eof(self) -> VerificationResult<()> {
         #[cfg(debug_assertions)]
-        assert!(self.pattern.is_empty());
+        assert!(self.pattern.is_none_or(<[_]>::is_empty));
         verify!(self.inner.check_eof().is_ok());
-        verify!(self.hints.is_empty());
+        match self.source {
+            Source::Proof { hints } => verify!(hints.is_empty()),
+            Source::Stream {
+                mut narg_string,
+                mut hints,
+            } => {
+                verify!(narg_string.is_eof()?);
+                verify!(hints.is_eof()?);
+            }
+        }
         Ok(())
     }
 
@@ -291,7 +356,14 @@ where
     {
         #[cfg(debug_assertions)]
         self.pop_pattern(&Interaction::ProverMessage(type_name::<T>().to_owned()));
-        self.inner.prover_message()
+        match &mut self.source {
+            Source::Proof { .. } => self.inner.prover_message(),
+            Source::Stream { narg_string, .. } => {
+                let message: T = narg_string.deserialize_narg()?;
+                self.inner.public_message(&message);
+                Ok(message)
+            }
+        }
     }
 
     #[cfg_attr(test, track_caller)]
@@ -309,7 +381,10 @@ where
     {
         #[cfg(debug_assertions)]
         self.pop_pattern(&Interaction::Hint(type_name::<T>().to_owned()));
-        T::deserialize_from_narg(&mut self.hints)
+        match &mut self.source {
+            Source::Proof { hints } => T::deserialize_from_narg(hints),
+            Source::Stream { hints, .. } => hints.deserialize_narg(),
+        }
     }
 
     #[cfg_attr(test, track_caller)]
@@ -319,19 +394,27 @@ where
     {
         #[cfg(debug_assertions)]
         self.pop_pattern(&Interaction::Hint(type_name::<T>().to_owned()));
-        T::deserialize_compressed(&mut self.hints).map_err(|_| VerificationError)
+        match &mut self.source {
+            Source::Proof { hints } => T::deserialize_compressed(hints),
+            Source::Stream { hints, .. } => T::deserialize_compressed(hints),
+        }
+        .map_err(|_| VerificationError)
     }
 
     #[cfg(debug_assertions)]
     #[track_caller]
     fn pop_pattern(&mut self, interaction: &Interaction) {
-        assert!(!self.pattern.is_empty());
-        let (expected, tail) = self.pattern.split_first().unwrap();
+        // Streamed proofs carry no pattern to check against.
+        let Some(pattern) = self.pattern else {
+            return;
+        };
+        assert!(!pattern.is_empty());
+        let (expected, tail) = pattern.split_first().unwrap();
         assert_eq!(
             interaction, expected,
             "Transcript error: Expected interaction {expected:?} got {interaction:?}"
         );
-        self.pattern = tail;
+        self.pattern = Some(tail);
     }
 }
 
@@ -343,6 +426,21 @@ impl<'a> VerifierState<'a, StdHash> {
     {
         Self::new(ds, proof, StdHash::default())
     }
+
+    /// Construct a streaming verifier state with the standard duplex hash function.
+    ///
+    /// See [`VerifierState::from_reader`].
+    pub fn from_reader_std<'b, I>(
+        ds: &DomainSeparator<'b, I>,
+        narg_string: impl Read + 'a,
+        hints: impl Read + 'a,
+        limits: StreamLimits,
+    ) -> Self
+    where
+        I: Encoding<[u8]>,
+    {
+        Self::from_reader(ds, narg_string, hints, limits, StdHash::default())
+    }
 }
 
 impl<H> VerifierMessage for VerifierState<'_, H>
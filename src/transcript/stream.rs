@@ -0,0 +1,120 @@
+//! Incremental reading of proof data from [`std::io::Read`] sources.
+
+use std::io::{self, Read};
+
+use super::{NargDeserialize, VerificationError, VerificationResult};
+use crate::verify;
+
+/// Resource limits for a [`super::VerifierState`] reading from streams.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StreamLimits {
+    /// Size in bytes of the read buffer for each source.
+    ///
+    /// Every individual prover message or hint must fit in this buffer.
+    pub buffer_size: usize,
+
+    /// Maximum number of bytes read from the NARG string source.
+    pub max_narg_bytes: u64,
+
+    /// Maximum number of bytes read from the hints source.
+    pub max_hint_bytes: u64,
+}
+
+/// Bounded read-ahead buffer over a [`Read`] source.
+///
+/// I/O errors and reading more than `limit` bytes in total reject the proof.
+pub(crate) struct ByteStream<'a> {
+    reader: Box<dyn Read + 'a>,
+    buffer: Box<[u8]>,
+    start: usize,
+    end: usize,
+    total: u64,
+    limit: u64,
+    eof: bool,
+}
+
+impl Default for StreamLimits {
+    fn default() -> Self {
+        Self {
+            buffer_size: 1 << 16,
+            max_narg_bytes: 1 << 24,
+            max_hint_bytes: 1 << 32,
+        }
+    }
+}
+
+impl<'a> ByteStream<'a> {
+    pub fn new(reader: impl Read + 'a, buffer_size: usize, limit: u64) -> Self {
+        assert!(buffer_size > 0, "Buffer size must be non-zero.");
+        Self {
+            reader: Box::new(reader),
+            buffer: vec![0; buffer_size].into_boxed_slice(),
+            start: 0,
+            end: 0,
+            total: 0,
+            limit,
+            eof: false,
+        }
+    }
+
+    /// Returns true if all bytes have been consumed and the source is exhausted.
+    pub fn is_eof(&mut self) -> VerificationResult<bool> {
+        if self.start == self.end {
+            self.fill()?;
+        }
+        Ok(self.start == self.end)
+    }
+
+    /// Deserialize a value from the front of the stream.
+    ///
+    /// Attempts deserialization on the buffered bytes first and only reads
+    /// more from the source if that fails.
+    pub fn deserialize_narg<T: NargDeserialize>(&mut self) -> VerificationResult<T> {
+        loop {
+            let mut window = &self.buffer[self.start..self.end];
+            let available = window.len();
+            if let Ok(value) = T::deserialize_from_narg(&mut window) {
+                self.start += available - window.len();
+                return Ok(value);
+            }
+            // Retry with more bytes, unless there are none to be had.
+            verify!(!self.eof && available < self.buffer.len());
+            self.fill()?;
+        }
+    }
+
+    /// Move the unconsumed bytes to the front and read until the buffer is full.
+    fn fill(&mut self) -> VerificationResult<()> {
+        if self.start > 0 {
+            self.buffer.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+        while !self.eof && self.end < self.buffer.len() {
+            match self.reader.read(&mut self.buffer[self.end..]) {
+                Ok(0) => self.eof = true,
+                Ok(n) => {
+                    self.end += n;
+                    self.total += n as u64;
+                    verify!(self.total <= self.limit);
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => return Err(VerificationError),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Read for ByteStream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.start == self.end {
+            self.fill()
+                .map_err(|_| io::Error::other("Proof stream rejected"))?;
+        }
+        let n = buf.len().min(self.end - self.start);
+        buf[..n].copy_from_slice(&self.buffer[self.start..self.start + n]);
+        self.start += n;
+        Ok(n)
+    }
+}